@@ -253,6 +253,54 @@ impl BitAnd for BooleanChunked {
     }
 }
 
+impl BooleanChunked {
+    /// Logical implication `self → other`, i.e. `!self | other`, with three-valued (Kleene)
+    /// logic: a `false` lhs or a `true` rhs gives `true` even if the other side is null,
+    /// otherwise null values propagate. The values and the validity are computed from the
+    /// bitmaps in one pass.
+    pub fn implies(&self, other: &BooleanChunked) -> Result<BooleanChunked> {
+        if self.len() != other.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "cannot compute the implication of boolean arrays of lengths {} and {}",
+                    self.len(),
+                    other.len()
+                )
+                .into(),
+            ));
+        }
+        let (lhs, rhs) = align_chunks_binary(self, other);
+        let chunks = lhs
+            .downcast_iter()
+            .zip(rhs.downcast_iter())
+            .map(|(l_arr, r_arr)| {
+                let not_lhs = !l_arr.values();
+                let values = &not_lhs | r_arr.values();
+                let validity = match (l_arr.validity(), r_arr.validity()) {
+                    (None, None) => None,
+                    (l_validity, r_validity) => {
+                        // a valid `false` lhs or a valid `true` rhs determines the result
+                        let known_false_lhs = match l_validity {
+                            Some(validity) => validity & &not_lhs,
+                            None => not_lhs,
+                        };
+                        let known_true_rhs = match r_validity {
+                            Some(validity) => validity & r_arr.values(),
+                            None => r_arr.values().clone(),
+                        };
+                        let both_valid = combine_validities(l_validity, r_validity).unwrap();
+                        Some(&(&both_valid | &known_false_lhs) | &known_true_rhs)
+                    }
+                };
+                let arr = BooleanArray::from_data_default(values, validity);
+                Box::new(arr) as ArrayRef
+            })
+            .collect::<Vec<_>>();
+
+        Ok(ChunkedArray::from_chunks(self.name(), chunks))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -267,4 +315,143 @@ mod test {
         assert_eq!((&a).bitor(&b).null_count(), 1);
         assert_eq!((&a).bitxor(&b).null_count(), 1);
     }
+
+    /// All nine combinations of `true`, `false` and null as `lhs` and `rhs`, in the order
+    /// (T, T), (T, F), (T, N), (F, T), (F, F), (F, N), (N, T), (N, F), (N, N).
+    fn truth_table() -> (Series, Series) {
+        let values = [Some(true), Some(false), None];
+        let lhs = values
+            .iter()
+            .flat_map(|l| values.iter().map(move |_| *l))
+            .collect::<Vec<_>>();
+        let rhs = values
+            .iter()
+            .flat_map(|_| values.iter().copied())
+            .collect::<Vec<_>>();
+        (Series::new("a", &lhs), Series::new("b", &rhs))
+    }
+
+    #[test]
+    fn test_kleene_truth_table() -> Result<()> {
+        let (lhs, rhs) = truth_table();
+        let out = SeriesTrait::bitand(lhs.as_ref(), &rhs)?;
+        assert_eq!(
+            Vec::from(out.bool()?),
+            &[
+                Some(true),
+                Some(false),
+                None,
+                Some(false),
+                Some(false),
+                Some(false),
+                None,
+                Some(false),
+                None
+            ]
+        );
+        let out = SeriesTrait::bitor(lhs.as_ref(), &rhs)?;
+        assert_eq!(
+            Vec::from(out.bool()?),
+            &[
+                Some(true),
+                Some(true),
+                Some(true),
+                Some(true),
+                Some(false),
+                None,
+                Some(true),
+                None,
+                None
+            ]
+        );
+
+        // broadcasting follows the same logic
+        let null = Series::new("n", &[None::<bool>]);
+        let out = SeriesTrait::bitor(null.as_ref(), &lhs)?;
+        assert_eq!(out.bool()?.get(0), Some(true));
+        assert_eq!(out.bool()?.get(4), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_implies_truth_table() -> Result<()> {
+        let (lhs, rhs) = truth_table();
+        let out = lhs.implies(&rhs)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(out.bool()?),
+            &[
+                Some(true),
+                Some(false),
+                None,
+                Some(true),
+                Some(true),
+                Some(true),
+                Some(true),
+                None,
+                None
+            ]
+        );
+
+        // without null values and over unaligned chunks
+        let mut lhs = BooleanChunked::new("a", &[true, true]);
+        lhs.append(&BooleanChunked::new("a", &[false, false]));
+        let rhs = BooleanChunked::new("b", &[true, false, true, false]);
+        let out = lhs.implies(&rhs)?;
+        assert_eq!(out.null_count(), 0);
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(true), Some(false), Some(true), Some(true)]
+        );
+
+        let lhs = lhs.into_series();
+        assert!(matches!(
+            lhs.implies(&lhs.head(Some(2))),
+            Err(PolarsError::ShapeMisMatch(_))
+        ));
+        let ints = Series::new("i", &[1, 2, 3, 4]);
+        assert!(ints.implies(&ints).is_err());
+        assert!(lhs.implies(&ints).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_xor_mask() -> Result<()> {
+        let first = Series::new(
+            "first",
+            &[Some(true), Some(false), None, Some(true), Some(false)],
+        );
+        let second = Series::new(
+            "second",
+            &[Some(true), Some(true), Some(true), Some(false), Some(false)],
+        );
+        let values = Series::new("values", &[1, 2, 3, 4, 5]);
+
+        let mask = first.xor_mask(&second)?;
+        let expected = SeriesTrait::bitxor(first.as_ref(), &second)?;
+        assert!(mask.clone().into_series().series_equal_missing(&expected));
+        // a null value in the mask is not selected
+        let out = values.filter(&mask)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(2), Some(4)]);
+
+        assert!(first.xor_mask(&second.head(Some(2))).is_err());
+        assert!(values.xor_mask(&values).is_err());
+        assert!(first.xor_mask(&values).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bool_unpack_error() {
+        let lhs = Series::new("a", &[true, false]);
+        let rhs = Series::new("b", &[1i32, 2]);
+
+        let check = |result: Result<Series>| match result {
+            Err(PolarsError::SchemaMisMatch(msg)) => assert!(msg.contains("Int32"), "{}", msg),
+            _ => panic!("expected a SchemaMisMatch error"),
+        };
+        check(rhs.bool().map(|ca| ca.clone().into_series()));
+        check(SeriesTrait::bitand(lhs.as_ref(), &rhs));
+        check(SeriesTrait::bitor(lhs.as_ref(), &rhs));
+        check(SeriesTrait::bitxor(lhs.as_ref(), &rhs));
+    }
 }
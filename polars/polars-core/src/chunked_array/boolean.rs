@@ -1,9 +1,992 @@
 use crate::prelude::*;
-use crate::utils::NoNull;
+use crate::utils::bitmap::bitmap_words;
+use crate::utils::{align_chunks_binary, combine_validities};
+use arrow::bitmap::{Bitmap, MutableBitmap};
+
+/// Push the indexes of the set bits in `word` offset by `base`.
+#[inline]
+fn push_set_bits(idx: &mut Vec<IdxSize>, mut word: u64, base: usize) {
+    while word != 0 {
+        idx.push((base + word.trailing_zeros() as usize) as IdxSize);
+        // clear the lowest set bit
+        word &= word - 1;
+    }
+}
 
 impl BooleanChunked {
+    /// Get the indexes of the `true` values. Null values are not `true`.
     pub fn arg_true(&self) -> IdxCa {
-        let ca: NoNull<IdxCa> = (0..self.len() as IdxSize).collect_trusted();
-        ca.into_inner().filter(self).unwrap()
+        let mut idx = Vec::with_capacity(self.count_true());
+        let mut offset = 0;
+        for arr in self.downcast_iter() {
+            let mask = match arr.validity() {
+                Some(validity) => arr.values() & validity,
+                None => arr.values().clone(),
+            };
+            for (i, word) in bitmap_words(&mask).enumerate() {
+                push_set_bits(&mut idx, word, offset + i * 64);
+            }
+            offset += arr.len();
+        }
+        IdxCa::from_vec("", idx)
+    }
+
+    /// Count the `true` values, null values are ignored.
+    pub fn count_true(&self) -> usize {
+        self.downcast_iter()
+            .map(|arr| match arr.validity() {
+                Some(validity) => arr.len() - (validity & arr.values()).null_count(),
+                None => arr.len() - arr.values().null_count(),
+            })
+            .sum()
+    }
+
+    /// Count the `false` values, null values are ignored.
+    pub fn count_false(&self) -> usize {
+        self.len() - self.null_count() - self.count_true()
+    }
+
+    /// Get the fraction of the non-null values that are `true`, `None` if all values are
+    /// null or the array is empty.
+    pub fn ratio_true(&self) -> Option<f64> {
+        let n_valid = self.len() - self.null_count();
+        if n_valid == 0 {
+            None
+        } else {
+            Some(self.count_true() as f64 / n_valid as f64)
+        }
+    }
+
+    /// Create a [`DataFrame`] with the unique values of this array and a column `"counts"`
+    /// with dtype [`IdxType`]. Values that don't occur are not included.
+    ///
+    /// # Arguments
+    ///
+    /// * `sort` - Sort by the counts in descending order, ties are ordered `true`, `false`, null.
+    /// * `include_nulls` - Count the null values as a separate row.
+    pub fn value_counts(&self, sort: bool, include_nulls: bool) -> Result<DataFrame> {
+        let null_count = if include_nulls { self.null_count() } else { 0 };
+        let mut counts = vec![
+            (Some(true), self.count_true()),
+            (Some(false), self.count_false()),
+            (None, null_count),
+        ];
+        counts.retain(|(_, count)| *count > 0);
+        if sort {
+            counts.sort_by(|a, b| b.1.cmp(&a.1));
+        }
+
+        let mut values: BooleanChunked = counts.iter().map(|(value, _)| *value).collect();
+        values.rename(self.name());
+        let counts = IdxCa::from_vec(
+            "counts",
+            counts.iter().map(|(_, count)| *count as IdxSize).collect(),
+        );
+        Ok(DataFrame::new_no_checks(vec![
+            values.into_series(),
+            counts.into_series(),
+        ]))
+    }
+}
+
+impl BooleanChunked {
+    /// Run-length encode the array. Returns a [`DataFrame`] with a column `"lengths"` with the
+    /// length of every run of equal consecutive values and a column `"values"` with the value
+    /// of the run. Consecutive null values form a run as well.
+    pub fn rle(&self) -> DataFrame {
+        let mut lengths = vec![];
+        let mut values = vec![];
+        let mut iter = self.into_iter();
+        if let Some(first) = iter.next() {
+            let mut current = first;
+            let mut length = 1 as IdxSize;
+            for opt_v in iter {
+                if opt_v == current {
+                    length += 1;
+                } else {
+                    lengths.push(length);
+                    values.push(current);
+                    current = opt_v;
+                    length = 1;
+                }
+            }
+            lengths.push(length);
+            values.push(current);
+        }
+
+        let lengths = IdxCa::from_vec("lengths", lengths);
+        let values = BooleanChunked::new("values", &values);
+        DataFrame::new_no_checks(vec![lengths.into_series(), values.into_series()])
+    }
+
+    /// Get the indexes where the value differs from the value in the previous row, i.e. the
+    /// start of every run of [`BooleanChunked::rle`] but the first. A null value is a state
+    /// of its own, so a change between null and `true` or `false` is a transition as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `include_first` - Include index `0`, which has no previous row to compare with.
+    pub fn transitions(&self, include_first: bool) -> IdxCa {
+        // compare every row with the previous one by shifting a copy by one row
+        let shifted = self.shift(1);
+        let (lhs, rhs) = align_chunks_binary(self, &shifted);
+        let chunks = lhs
+            .downcast_iter()
+            .zip(rhs.downcast_iter())
+            .map(|(lhs, rhs)| {
+                let changed = lhs.values() ^ rhs.values();
+                let mask = match (lhs.validity(), rhs.validity()) {
+                    (None, None) => changed,
+                    (l_validity, r_validity) => {
+                        let set = || Bitmap::from(MutableBitmap::from_len_set(lhs.len()));
+                        let l_validity = l_validity.cloned().unwrap_or_else(set);
+                        let r_validity = r_validity.cloned().unwrap_or_else(set);
+                        let both_valid = &l_validity & &r_validity;
+                        &(&l_validity ^ &r_validity) | &(&changed & &both_valid)
+                    }
+                };
+                Box::new(BooleanArray::from_data_default(mask, None)) as ArrayRef
+            })
+            .collect();
+        let idx = BooleanChunked::from_chunks("", chunks).arg_true();
+
+        let first_included = idx.get(0) == Some(0);
+        if include_first && !first_included && !self.is_empty() {
+            IdxCa::from_vec(
+                "",
+                std::iter::once(0).chain(idx.into_no_null_iter()).collect(),
+            )
+        } else if !include_first && first_included {
+            idx.slice(1, idx.len() - 1)
+        } else {
+            idx
+        }
+    }
+
+    /// Get the `(start, end)` ranges, `end` exclusive, of every run of consecutive `true`
+    /// values, i.e. the runs of [`BooleanChunked::rle`] with value `true`. A null value is
+    /// not `true` and ends a run.
+    pub fn true_ranges(&self) -> Vec<(usize, usize)> {
+        let mut ranges = vec![];
+        let mut start = None;
+        for (i, opt_v) in self.into_iter().enumerate() {
+            match (opt_v, start) {
+                (Some(true), None) => start = Some(i),
+                (Some(false) | None, Some(s)) => {
+                    ranges.push((s, i));
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(s) = start {
+            ranges.push((s, self.len()));
+        }
+        ranges
+    }
+}
+
+impl BooleanChunked {
+    /// Count the positions where `self` and `other` differ.
+    ///
+    /// # Arguments
+    ///
+    /// * `nulls_differ` - If `true`, a position where either side is null counts as a
+    ///   difference, otherwise these positions are skipped.
+    pub fn hamming_distance(&self, other: &BooleanChunked, nulls_differ: bool) -> Result<usize> {
+        if self.len() != other.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "hamming distance requires arrays of equal length, got {} and {}",
+                    self.len(),
+                    other.len()
+                )
+                .into(),
+            ));
+        }
+        let (lhs, rhs) = align_chunks_binary(self, other);
+        let distance = lhs
+            .downcast_iter()
+            .zip(rhs.downcast_iter())
+            .map(|(lhs, rhs)| {
+                let diff = lhs.values() ^ rhs.values();
+                match combine_validities(lhs.validity(), rhs.validity()) {
+                    Some(validity) => {
+                        let n_nulls = validity.null_count();
+                        let n_diff = diff.len() - (&diff & &validity).null_count();
+                        if nulls_differ {
+                            n_diff + n_nulls
+                        } else {
+                            n_diff
+                        }
+                    }
+                    None => diff.len() - diff.null_count(),
+                }
+            })
+            .sum();
+        Ok(distance)
+    }
+
+    /// Sum the `weights` at the positions where `self` is `true`. Positions where either side
+    /// is null are skipped. The `weights` must be numeric and of the same length.
+    pub fn weighted_true_sum(&self, weights: &Series) -> Result<f64> {
+        if self.len() != weights.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "weighted sum requires weights of equal length, got {} and {}",
+                    self.len(),
+                    weights.len()
+                )
+                .into(),
+            ));
+        }
+        if !weights.dtype().is_numeric() {
+            return Err(PolarsError::SchemaMisMatch(
+                format!("weights must be numeric, got dtype {:?}", weights.dtype()).into(),
+            ));
+        }
+        let weights = weights.cast(&DataType::Float64)?;
+        let mask = self.fill_null_with_values(false)?;
+        Ok(weights.f64()?.filter(&mask)?.sum().unwrap_or(0.0))
+    }
+
+    /// Get the elementwise minimum of `self` and `other` with `false < true`, i.e. the
+    /// logical AND. As in `DataFrame::hmin`, null values are ignored, so the result is only
+    /// null where both sides are null.
+    pub fn min_elementwise(&self, other: &BooleanChunked) -> Result<Self> {
+        self.combine_ignore_nulls(other, "min_elementwise", true, |lhs, rhs| lhs & rhs)
+    }
+
+    /// Get the elementwise maximum of `self` and `other` with `false < true`, i.e. the
+    /// logical OR. As in `DataFrame::hmax`, null values are ignored, so the result is only
+    /// null where both sides are null.
+    pub fn max_elementwise(&self, other: &BooleanChunked) -> Result<Self> {
+        self.combine_ignore_nulls(other, "max_elementwise", false, |lhs, rhs| lhs | rhs)
+    }
+
+    /// Combine the values of `self` and `other` with `op`, after replacing the null values
+    /// by `identity`, the value for which `op` returns the other side.
+    fn combine_ignore_nulls(
+        &self,
+        other: &BooleanChunked,
+        name: &str,
+        identity: bool,
+        op: impl Fn(&Bitmap, &Bitmap) -> Bitmap,
+    ) -> Result<Self> {
+        if self.len() != other.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "{} requires arrays of equal length, got {} and {}",
+                    name,
+                    self.len(),
+                    other.len()
+                )
+                .into(),
+            ));
+        }
+        let fill = |arr: &BooleanArray| match arr.validity() {
+            Some(validity) if identity => arr.values() | &!validity,
+            Some(validity) => arr.values() & validity,
+            None => arr.values().clone(),
+        };
+        let (lhs, rhs) = align_chunks_binary(self, other);
+        let chunks = lhs
+            .downcast_iter()
+            .zip(rhs.downcast_iter())
+            .map(|(lhs, rhs)| {
+                let values = op(&fill(lhs), &fill(rhs));
+                let validity = match (lhs.validity(), rhs.validity()) {
+                    (Some(l_validity), Some(r_validity)) => Some(l_validity | r_validity),
+                    _ => None,
+                };
+                Box::new(BooleanArray::from_data_default(values, validity)) as ArrayRef
+            })
+            .collect();
+        Ok(BooleanChunked::from_chunks(self.name(), chunks))
+    }
+}
+
+/// Get the length of the shortest prefix of `arr` that contains `n` valid values.
+fn prefix_len_with_n_valid(arr: &BooleanArray, n: usize) -> usize {
+    match arr.validity() {
+        None => n,
+        Some(_) if n == 0 => 0,
+        Some(validity) => {
+            let mut seen = 0;
+            for (i, valid) in validity.iter().enumerate() {
+                seen += valid as usize;
+                if seen == n {
+                    return i + 1;
+                }
+            }
+            arr.len()
+        }
+    }
+}
+
+impl BooleanChunked {
+    /// Get the first `n` non-null values. Only the chunks up to the `n`th non-null value are
+    /// visited, the plain [`ChunkOps::head`] keeps the null values.
+    pub fn head_non_null(&self, n: usize) -> BooleanChunked {
+        let mut remaining = n;
+        let mut end = 0;
+        for arr in self.downcast_iter() {
+            let n_valid = arr.len() - arr.null_count();
+            if n_valid >= remaining {
+                end += prefix_len_with_n_valid(arr, remaining);
+                break;
+            }
+            remaining -= n_valid;
+            end += arr.len();
+        }
+        self.slice(0, end).drop_nulls()
+    }
+
+    /// Get the last `n` non-null values. Only the chunks from the `n`th last non-null value
+    /// on are visited, the plain [`ChunkOps::tail`] keeps the null values.
+    pub fn tail_non_null(&self, n: usize) -> BooleanChunked {
+        let mut remaining = n;
+        let mut start = self.len();
+        for arr in self.downcast_iter().rev() {
+            let n_valid = arr.len() - arr.null_count();
+            start -= arr.len();
+            if n_valid >= remaining {
+                // skip the valid values of this chunk that come before the last `remaining`
+                start += prefix_len_with_n_valid(arr, n_valid - remaining);
+                break;
+            }
+            remaining -= n_valid;
+        }
+        self.slice(start as i64, self.len() - start).drop_nulls()
+    }
+
+    fn drop_nulls(&self) -> BooleanChunked {
+        if self.null_count() == 0 {
+            self.clone()
+        } else {
+            self.filter(&self.is_not_null()).unwrap()
+        }
+    }
+}
+
+/// Pack the bits of the given bitmaps into a single byte buffer that starts at bit offset `0`.
+fn pack_bitmaps(bitmaps: Vec<Bitmap>, len: usize) -> Vec<u8> {
+    let n_bytes = (len + 7) / 8;
+    if let [bitmap] = bitmaps.as_slice() {
+        let (bytes, offset, _) = bitmap.as_slice();
+        if offset == 0 {
+            return bytes[..n_bytes].to_vec();
+        }
+    }
+    let bits: MutableBitmap = bitmaps.iter().flat_map(|bitmap| bitmap.iter()).collect();
+    bits.as_slice()[..n_bytes].to_vec()
+}
+
+impl BooleanChunked {
+    /// Get the values as a packed bitmap, least significant bit first, together with the
+    /// number of bits. Null values are packed as `false`, use
+    /// [`BooleanChunked::validity_to_bitmap`] to get the null mask.
+    pub fn to_bitmap(&self) -> (Vec<u8>, usize) {
+        let bitmaps = self
+            .downcast_iter()
+            .map(|arr| match arr.validity() {
+                Some(validity) => arr.values() & validity,
+                None => arr.values().clone(),
+            })
+            .collect();
+        (pack_bitmaps(bitmaps, self.len()), self.len())
+    }
+
+    /// Get the validity as a packed bitmap, least significant bit first, where a set bit
+    /// marks a valid value. Returns `None` if there are no null values.
+    pub fn validity_to_bitmap(&self) -> Option<Vec<u8>> {
+        if self.null_count() == 0 {
+            return None;
+        }
+        let bitmaps = self
+            .downcast_iter()
+            .map(|arr| match arr.validity() {
+                Some(validity) => validity.clone(),
+                None => MutableBitmap::from_len_set(arr.len()).into(),
+            })
+            .collect();
+        Some(pack_bitmaps(bitmaps, self.len()))
+    }
+
+    /// Create a new [`BooleanChunked`] from a packed bitmap, least significant bit first,
+    /// with `len` bits. The buffer is moved into the array, not copied.
+    pub fn from_bitmap(name: &str, bytes: Vec<u8>, len: usize) -> Result<Self> {
+        if len > bytes.len() * 8 {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "cannot create a boolean array of length {} from {} bytes",
+                    len,
+                    bytes.len()
+                )
+                .into(),
+            ));
+        }
+        let values = Bitmap::from_u8_vec(bytes, len);
+        Ok((name, BooleanArray::from_data_default(values, None)).into())
+    }
+}
+
+impl BooleanChunked {
+    /// Get a copy of `self` where the value at every index in `idx` is replaced by the value
+    /// at the same position in `values`. A null in `values` sets a null.
+    ///
+    /// If an index occurs more than once, the last write wins. Null and out of bounds indices
+    /// result in an error.
+    pub fn scatter(&self, idx: &IdxCa, values: &BooleanChunked) -> Result<Self> {
+        if idx.len() != values.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "scatter requires as many values as indices, got {} values and {} indices",
+                    values.len(),
+                    idx.len()
+                )
+                .into(),
+            ));
+        }
+        let len = self.len();
+        let mut bits = MutableBitmap::with_capacity(len);
+        let mut validity = (self.has_validity() || values.has_validity())
+            .then(|| MutableBitmap::with_capacity(len));
+        for arr in self.downcast_iter() {
+            bits.extend_from_bitmap(arr.values());
+            if let Some(validity) = &mut validity {
+                match arr.validity() {
+                    Some(v) => validity.extend_from_bitmap(v),
+                    None => validity.extend_constant(arr.len(), true),
+                }
+            }
+        }
+
+        for (opt_i, opt_v) in idx.into_iter().zip(values.into_iter()) {
+            let i = match opt_i {
+                Some(i) if (i as usize) < len => i as usize,
+                Some(i) => {
+                    return Err(PolarsError::ComputeError(
+                        format!("index {} is out of bounds for length {}", i, len).into(),
+                    ))
+                }
+                None => {
+                    return Err(PolarsError::ComputeError(
+                        "scatter indices cannot be null".into(),
+                    ))
+                }
+            };
+            match opt_v {
+                Some(v) => {
+                    bits.set(i, v);
+                    if let Some(validity) = &mut validity {
+                        validity.set(i, true);
+                    }
+                }
+                // validity is always allocated if `values` has nulls
+                None => validity.as_mut().unwrap().set(i, false),
+            }
+        }
+        let arr = BooleanArray::from_data_default(bits.into(), validity.map(|v| v.into()));
+        Ok((self.name(), arr).into())
+    }
+
+    /// Get a copy of `self` where every row of the group at index `i` in `groups` is set to
+    /// the value at index `i` in `values`, e.g. to broadcast the result of an aggregation back
+    /// to the rows of its group. Rows that are not in any group keep their value.
+    ///
+    /// The result is the same as [`BooleanChunked::scatter`] with the indices of all groups
+    /// and every value repeated for the length of its group, but the rows are combined with
+    /// bitmap operations instead of being written one value at a time. The groups must not
+    /// overlap.
+    pub fn scatter_from_groups(
+        &self,
+        groups: &GroupsProxy,
+        values: &BooleanChunked,
+    ) -> Result<Self> {
+        if groups.len() != values.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "scatter_from_groups requires a value per group, got {} values and {} groups",
+                    values.len(),
+                    groups.len()
+                )
+                .into(),
+            ));
+        }
+        let len = self.len();
+        // the rows in a group, the rows set to `true` and the rows set to a non-null value
+        let mut in_group = MutableBitmap::from_len_zeroed(len);
+        let mut set_true = MutableBitmap::from_len_zeroed(len);
+        let mut set_valid = MutableBitmap::from_len_zeroed(len);
+        let mut mark = |i: usize, value: Option<bool>| {
+            if i >= len {
+                return Err(PolarsError::ComputeError(
+                    format!("index {} is out of bounds for length {}", i, len).into(),
+                ));
+            }
+            in_group.set(i, true);
+            if let Some(v) = value {
+                set_true.set(i, v);
+                set_valid.set(i, true);
+            }
+            Ok(())
+        };
+        match groups {
+            GroupsProxy::Idx(groups) => {
+                for (idx, value) in groups.all().iter().zip(values.into_iter()) {
+                    for &i in idx {
+                        mark(i as usize, value)?;
+                    }
+                }
+            }
+            GroupsProxy::Slice { groups, .. } => {
+                for (&[first, group_len], value) in groups.iter().zip(values.into_iter()) {
+                    for i in first..first + group_len {
+                        mark(i as usize, value)?;
+                    }
+                }
+            }
+        }
+
+        let ca = self.rechunk();
+        let arr = ca.downcast_iter().next().unwrap();
+        let keep = !&Bitmap::from(in_group);
+        let bits = &(arr.values() & &keep) | &set_true.into();
+        let validity = (self.has_validity() || values.has_validity()).then(|| {
+            let set_valid = set_valid.into();
+            match arr.validity() {
+                Some(validity) => &(validity & &keep) | &set_valid,
+                None => &keep | &set_valid,
+            }
+        });
+        let arr = BooleanArray::from_data_default(bits, validity);
+        Ok((self.name(), arr).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_value_counts() -> Result<()> {
+        let ca = BooleanChunked::new(
+            "a",
+            &[
+                Some(true),
+                Some(false),
+                None,
+                Some(true),
+                Some(false),
+                Some(true),
+            ],
+        );
+
+        let df = ca.value_counts(true, true)?;
+        assert_eq!(df.get_column_names(), &["a", "counts"]);
+        assert_eq!(
+            Vec::from(df.column("a")?.bool()?),
+            &[Some(true), Some(false), None]
+        );
+        assert_eq!(
+            Vec::from(df.column("counts")?.idx()?),
+            &[Some(3), Some(2), Some(1)]
+        );
+
+        let df = ca.value_counts(false, false)?;
+        assert_eq!(
+            Vec::from(df.column("a")?.bool()?),
+            &[Some(true), Some(false)]
+        );
+        assert_eq!(Vec::from(df.column("counts")?.idx()?), &[Some(3), Some(2)]);
+
+        let df = ca.into_series().value_counts(false)?;
+        assert_eq!(
+            Vec::from(df.column("counts")?.idx()?),
+            &[Some(3), Some(2), Some(1)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rle() -> Result<()> {
+        let s = Series::new(
+            "a",
+            &[Some(true), Some(true), Some(false), Some(true), None, None],
+        );
+        let df = s.rle()?;
+        assert_eq!(
+            Vec::from(df.column("lengths")?.idx()?),
+            &[Some(2), Some(1), Some(1), Some(2)]
+        );
+        assert_eq!(
+            Vec::from(df.column("values")?.bool()?),
+            &[Some(true), Some(false), Some(true), None]
+        );
+
+        let df = BooleanChunked::new("a", &[] as &[bool]).rle();
+        assert_eq!(df.height(), 0);
+        assert!(Series::new("a", &[1, 2]).rle().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_min_max_elementwise() -> Result<()> {
+        let values = [Some(true), Some(false), None];
+        let lhs: BooleanChunked = values
+            .iter()
+            .flat_map(|l| values.iter().map(move |_| *l))
+            .collect();
+        let rhs: BooleanChunked = values.iter().cycle().take(9).copied().collect();
+
+        let min = lhs.min_elementwise(&rhs)?;
+        let max = lhs.max_elementwise(&rhs)?;
+        let expected_min = lhs
+            .into_iter()
+            .zip(rhs.into_iter())
+            .map(|(l, r)| match (l, r) {
+                (Some(l), Some(r)) => Some(l && r),
+                (l, r) => l.or(r),
+            })
+            .collect::<Vec<_>>();
+        let expected_max = lhs
+            .into_iter()
+            .zip(rhs.into_iter())
+            .map(|(l, r)| match (l, r) {
+                (Some(l), Some(r)) => Some(l || r),
+                (l, r) => l.or(r),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(Vec::from(&min), expected_min);
+        assert_eq!(Vec::from(&max), expected_max);
+
+        // the same as the numeric minimum and maximum of the values as 0 and 1
+        #[cfg(feature = "zip_with")]
+        {
+            let frame = |mut lhs: Series, mut rhs: Series| {
+                lhs.rename("lhs");
+                rhs.rename("rhs");
+                DataFrame::new(vec![lhs, rhs])
+            };
+            let df = frame(lhs.cast(&DataType::UInt8)?, rhs.cast(&DataType::UInt8)?)?;
+            let min_num = df.hmin()?.unwrap().cast(&DataType::Boolean)?;
+            let max_num = df.hmax()?.unwrap().cast(&DataType::Boolean)?;
+            assert_eq!(Vec::from(min_num.bool()?), expected_min);
+            assert_eq!(Vec::from(max_num.bool()?), expected_max);
+
+            // boolean columns take the same path in the horizontal aggregations
+            let df = frame(lhs.clone().into_series(), rhs.clone().into_series())?;
+            assert_eq!(Vec::from(df.hmin()?.unwrap().bool()?), expected_min);
+            assert_eq!(Vec::from(df.hmax()?.unwrap().bool()?), expected_max);
+        }
+
+        let s = lhs.into_series();
+        assert!(s.min_elementwise(&rhs.into_series()).is_ok());
+        assert!(s.max_elementwise(&s.slice(0, 2)).is_err());
+        assert!(Series::new("a", &[1, 2])
+            .min_elementwise(&Series::new("b", &[1, 2]))
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_true_ranges() {
+        let ca = BooleanChunked::new("a", &[false, true, true, false, true]);
+        assert_eq!(ca.true_ranges(), &[(1, 3), (4, 5)]);
+
+        let ca = BooleanChunked::new(
+            "a",
+            &[Some(true), Some(true), None, Some(true), Some(false)],
+        );
+        assert_eq!(ca.true_ranges(), &[(0, 2), (3, 4)]);
+        assert!(BooleanChunked::new("a", &[false, false])
+            .true_ranges()
+            .is_empty());
+        assert!(Series::new("a", &[1, 2]).true_ranges().is_err());
+    }
+
+    #[test]
+    fn test_transitions() -> Result<()> {
+        let s = Series::new("a", &[true, true, false, false, true]);
+        assert_eq!(Vec::from(&s.transitions(false)?), &[Some(2), Some(4)]);
+        assert_eq!(
+            Vec::from(&s.transitions(true)?),
+            &[Some(0), Some(2), Some(4)]
+        );
+
+        // null is a state of its own, also over chunk boundaries
+        let mut ca = BooleanChunked::new("a", &[Some(true), None, None]);
+        ca.append(&BooleanChunked::new("a", &[Some(true), Some(false), None]));
+        assert_eq!(
+            Vec::from(&ca.transitions(false)),
+            &[Some(1), Some(3), Some(4), Some(5)]
+        );
+        // the starts of the runs
+        let s = ca.into_series();
+        let lengths = s.rle()?;
+        let lengths = lengths.column("lengths")?.idx()?;
+        let starts = lengths
+            .into_no_null_iter()
+            .scan(0, |start, len| {
+                let out = *start;
+                *start += len;
+                Some(out)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            s.transitions(true)?.into_no_null_iter().collect::<Vec<_>>(),
+            starts
+        );
+
+        let ca = BooleanChunked::new("a", &[None, None, Some(false)]);
+        assert_eq!(Vec::from(&ca.transitions(false)), &[Some(2)]);
+        assert_eq!(Vec::from(&ca.transitions(true)), &[Some(0), Some(2)]);
+        assert_eq!(
+            BooleanChunked::new("a", &[] as &[bool])
+                .transitions(true)
+                .len(),
+            0
+        );
+        assert!(Series::new("a", &[1, 2]).transitions(false).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_weighted_true_sum() -> Result<()> {
+        let s = Series::new(
+            "a",
+            &[Some(true), Some(false), None, Some(true), Some(true)],
+        );
+        let weights = Series::new("w", &[Some(1.5), Some(2.0), Some(4.0), None, Some(0.25)]);
+        assert_eq!(s.weighted_true_sum(&weights)?, 1.75);
+
+        // integer weights are summed as floats
+        let weights = Series::new("w", &[1i32, 2, 3, 4, 5]);
+        assert_eq!(s.weighted_true_sum(&weights)?, 10.0);
+        let none = Series::new("a", &[false, false, false, false, false]);
+        assert_eq!(none.weighted_true_sum(&weights)?, 0.0);
+
+        assert!(matches!(
+            s.weighted_true_sum(&weights.head(Some(2))),
+            Err(PolarsError::ShapeMisMatch(_))
+        ));
+        let strings = Series::new("w", &["a", "b", "c", "d", "e"]);
+        assert!(matches!(
+            s.weighted_true_sum(&strings),
+            Err(PolarsError::SchemaMisMatch(_))
+        ));
+        assert!(weights.weighted_true_sum(&weights).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_true_false() {
+        let mut ca = BooleanChunked::new("a", &[Some(true), None, Some(false), Some(true)]);
+        ca.append(&BooleanChunked::new("a", &[None, Some(true)]));
+        let s = ca.into_series();
+
+        assert_eq!(s.count_true().unwrap(), 3);
+        assert_eq!(s.count_false().unwrap(), 1);
+        assert_eq!(
+            s.count_true().unwrap() + s.count_false().unwrap() + s.null_count(),
+            s.len()
+        );
+        assert!(Series::new("a", &[1, 2]).count_true().is_err());
+    }
+
+    #[test]
+    fn test_ratio_true() {
+        let ca = BooleanChunked::new("a", &[true, true, true]);
+        assert_eq!(ca.ratio_true(), Some(1.0));
+        let ca = BooleanChunked::new("a", &[None, None] as &[Option<bool>]);
+        assert_eq!(ca.ratio_true(), None);
+        assert_eq!(BooleanChunked::new("a", &[] as &[bool]).ratio_true(), None);
+
+        // the null values are not part of the denominator
+        let s = Series::new("a", &[Some(true), None, Some(false), Some(true), None]);
+        assert_eq!(s.ratio_true().unwrap(), Some(2.0 / 3.0));
+        assert!(Series::new("a", &[1, 2]).ratio_true().is_err());
+    }
+
+    #[test]
+    fn test_bitmap_round_trip() -> Result<()> {
+        let values = (0..130).map(|i| i % 3 == 0).collect::<Vec<_>>();
+        let ca = BooleanChunked::new("a", &values);
+
+        let (bytes, len) = ca.to_bitmap();
+        assert_eq!(len, 130);
+        assert_eq!(bytes.len(), 17);
+        assert_eq!(bytes[0], 0b0100_1001);
+        assert!(ca.validity_to_bitmap().is_none());
+
+        let out = BooleanChunked::from_bitmap("a", bytes, len)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(&out), Vec::from(&ca));
+
+        // sliced and chunked arrays are packed from bit offset 0
+        let mut sliced = ca.slice(3, 127);
+        sliced.append(&BooleanChunked::new("a", &[true, false, true]));
+        let (bytes, len) = sliced.to_bitmap();
+        let out = BooleanChunked::from_bitmap("a", bytes, len)?;
+        assert_eq!(Vec::from(&out), Vec::from(&sliced));
+
+        // nulls are packed as false
+        let ca = BooleanChunked::new("a", &[Some(true), None, Some(true), Some(false)]);
+        assert_eq!(ca.to_bitmap(), (vec![0b0000_0101], 4));
+        assert_eq!(ca.validity_to_bitmap(), Some(vec![0b0000_1101]));
+
+        assert!(BooleanChunked::from_bitmap("a", vec![0u8; 2], 17).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_hamming_distance() -> Result<()> {
+        let a = (0..1000).map(|i| i % 2 == 0).collect::<Vec<_>>();
+        let b = (0..1000).map(|i| i % 4 == 0).collect::<Vec<_>>();
+        let expected = a.iter().zip(&b).filter(|(a, b)| a != b).count();
+        assert_eq!(expected, 250);
+
+        let a = Series::new("a", &a);
+        let b = Series::new("b", &b);
+        assert_eq!(a.hamming_distance(&b, false)?, expected);
+        assert_eq!(a.hamming_distance(&a, true)?, 0);
+
+        // differently chunked input
+        let mut b_chunked = b.slice(0, 300);
+        b_chunked.append(&b.slice(300, 700))?;
+        assert_eq!(a.hamming_distance(&b_chunked, false)?, expected);
+
+        // nulls
+        let a = BooleanChunked::new("a", &[Some(true), None, Some(false), None, Some(true)]);
+        let b = BooleanChunked::new(
+            "b",
+            &[Some(false), Some(true), Some(false), None, Some(true)],
+        );
+        assert_eq!(a.hamming_distance(&b, false)?, 1);
+        assert_eq!(a.hamming_distance(&b, true)?, 3);
+
+        assert!(matches!(
+            a.hamming_distance(&b.slice(0, 4), true),
+            Err(PolarsError::ShapeMisMatch(_))
+        ));
+        let ints = Series::new("i", &[1, 2]);
+        assert!(ints.hamming_distance(&ints, true).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_arg_true_sparse() {
+        let n = 1_000_000;
+        let trues = [0, 63, 64, 65, 4097, 500_000, 999_999];
+        let nulls = [1, 64, 800_000];
+        let values = (0..n)
+            .map(|i| {
+                if nulls.contains(&i) {
+                    None
+                } else {
+                    Some(trues.contains(&i))
+                }
+            })
+            .collect::<Vec<_>>();
+        let ca = BooleanChunked::new("a", &values);
+
+        let naive = |ca: &BooleanChunked| {
+            ca.into_iter()
+                .enumerate()
+                .filter_map(|(i, v)| (v == Some(true)).then(|| Some(i as IdxSize)))
+                .collect::<Vec<_>>()
+        };
+
+        let out = ca.arg_true();
+        assert_eq!(out.name(), "");
+        assert_eq!(
+            Vec::from(&out),
+            &[
+                Some(0),
+                Some(63),
+                Some(65),
+                Some(4097),
+                Some(500_000),
+                Some(999_999)
+            ]
+        );
+        assert_eq!(Vec::from(&out), naive(&ca));
+
+        // sliced and chunked arrays don't start at a word boundary
+        let mut sliced = ca.slice(3, 600_000);
+        sliced.append(&ca.slice(700_003, 299_997));
+        assert_eq!(Vec::from(&sliced.arg_true()), naive(&sliced));
+    }
+
+    #[test]
+    fn test_head_tail_non_null() -> Result<()> {
+        let mut ca = BooleanChunked::new("a", &[None, Some(true), None, Some(false)]);
+        ca.append(&BooleanChunked::new(
+            "a",
+            &[Some(true), None, Some(true), None],
+        ));
+
+        // the plain head and tail keep the nulls
+        assert_eq!(Vec::from(&ca.head(Some(3))), &[None, Some(true), None]);
+        assert_eq!(Vec::from(&ca.tail(Some(2))), &[Some(true), None]);
+        let s = ca.clone().into_series();
+        assert_eq!(s.head(Some(3)).null_count(), 2);
+
+        assert_eq!(
+            Vec::from(&ca.head_non_null(3)),
+            &[Some(true), Some(false), Some(true)]
+        );
+        assert_eq!(Vec::from(&ca.head_non_null(1)), &[Some(true)]);
+        assert_eq!(
+            Vec::from(&ca.tail_non_null(3)),
+            &[Some(false), Some(true), Some(true)]
+        );
+        assert_eq!(Vec::from(&ca.tail_non_null(1)), &[Some(true)]);
+        assert_eq!(ca.head_non_null(0).len(), 0);
+        assert_eq!(ca.tail_non_null(0).len(), 0);
+        assert_eq!(ca.head_non_null(10).len(), 4);
+        assert_eq!(ca.tail_non_null(10).len(), 4);
+
+        let out = s.tail_non_null(2)?;
+        assert_eq!(Vec::from(out.bool()?), &[Some(true), Some(true)]);
+        assert!(Series::new("a", &[1, 2]).head_non_null(1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_scatter() -> Result<()> {
+        let mut ca = BooleanChunked::new("a", &[Some(true), Some(false), None]);
+        ca.append(&BooleanChunked::new("a", &[Some(false), Some(true)]));
+
+        let idx = IdxCa::new("idx", &[0, 3, 4]);
+        let values = BooleanChunked::new("values", &[Some(false), Some(true), None]);
+        let out = ca.scatter(&idx, &values)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(false), Some(false), None, Some(true), None]
+        );
+        assert_eq!(out.name(), "a");
+
+        // the last write wins
+        let idx = IdxCa::new("idx", &[1, 1]);
+        let values = BooleanChunked::new("values", &[false, true]);
+        assert_eq!(ca.scatter(&idx, &values)?.get(1), Some(true));
+
+        let idx = IdxCa::new("idx", &[5]);
+        let values = BooleanChunked::new("values", &[true]);
+        assert!(ca.scatter(&idx, &values).is_err());
+        let idx = IdxCa::new("idx", &[0, 1]);
+        assert!(ca.scatter(&idx, &values).is_err());
+
+        let s = ca.into_series();
+        let idx = IdxCa::new("idx", &[2]);
+        let out = s.scatter(&idx, &Series::new("values", &[true]))?;
+        assert_eq!(out.bool()?.get(2), Some(true));
+        assert!(s.scatter(&idx, &Series::new("values", &[1])).is_err());
+        assert!(Series::new("a", &[1, 2])
+            .scatter(&idx, &Series::new("values", &[true]))
+            .is_err());
+        Ok(())
     }
 }
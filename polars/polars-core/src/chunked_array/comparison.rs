@@ -851,6 +851,54 @@ impl BooleanChunked {
     pub fn any(&self) -> bool {
         self.downcast_iter().any(compute::boolean::any)
     }
+
+    /// Check if all values are `true`. Unlike [`BooleanChunked::all`], null values are
+    /// ignored, so this returns `true` if the array is empty or only contains null values.
+    pub fn all_ignore_nulls(&self) -> bool {
+        self.downcast_iter().all(|arr| match arr.validity() {
+            // no valid `false` value: `!values & validity` has no set bits
+            Some(validity) if arr.null_count() > 0 => {
+                (&!arr.values() & validity).null_count() == arr.len()
+            }
+            _ => arr.values().null_count() == 0,
+        })
+    }
+
+    /// Check if any value is `true`. Null values are ignored, so this returns `false`
+    /// if the array is empty or only contains null values.
+    pub fn any_ignore_nulls(&self) -> bool {
+        self.downcast_iter().any(|arr| match arr.validity() {
+            // a valid `true` value: `values & validity` has a set bit
+            Some(validity) if arr.null_count() > 0 => {
+                (arr.values() & validity).null_count() < arr.len()
+            }
+            _ => arr.values().null_count() < arr.len(),
+        })
+    }
+
+    /// Check if all values are `true` according to Kleene logic: returns `Some(false)`
+    /// if any value is `false`, `None` if there are null values and otherwise `Some(true)`.
+    pub fn all_kleene(&self) -> Option<bool> {
+        if !self.all_ignore_nulls() {
+            Some(false)
+        } else if self.null_count() > 0 {
+            None
+        } else {
+            Some(true)
+        }
+    }
+
+    /// Check if any value is `true` according to Kleene logic: returns `Some(true)`
+    /// if any value is `true`, `None` if there are null values and otherwise `Some(false)`.
+    pub fn any_kleene(&self) -> Option<bool> {
+        if self.any_ignore_nulls() {
+            Some(true)
+        } else if self.null_count() > 0 {
+            None
+        } else {
+            Some(false)
+        }
+    }
 }
 
 // private
@@ -879,12 +927,34 @@ where
     }
 }
 
+impl BooleanChunked {
+    /// Get the validity bit and the value bit at `index`.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be in bounds.
+    #[inline]
+    unsafe fn get_bits_unchecked(&self, index: usize) -> (bool, bool) {
+        debug_assert!(index < self.len());
+        let (chunk_idx, arr_idx) = self.index_to_chunked_index(index);
+        let arr = self.downcast_chunks().get_unchecked(chunk_idx);
+        let is_valid = arr
+            .validity()
+            .map_or(true, |validity| validity.get_bit_unchecked(arr_idx));
+        (is_valid, arr.values().get_bit_unchecked(arr_idx))
+    }
+}
+
 impl ChunkEqualElement for BooleanChunked {
     unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
         let ca_other = other.as_ref().as_ref();
         debug_assert!(self.dtype() == other.dtype());
         let ca_other = &*(ca_other as *const BooleanChunked);
-        self.get(idx_self) == ca_other.get(idx_other)
+        // compare the bits directly, the value bit of a null is undefined
+        // two nulls are considered equal
+        let (valid_self, value_self) = self.get_bits_unchecked(idx_self);
+        let (valid_other, value_other) = ca_other.get_bits_unchecked(idx_other);
+        valid_self == valid_other && (!valid_self || value_self == value_other)
     }
 }
 
@@ -958,6 +1028,66 @@ mod test {
     use crate::prelude::*;
     use std::iter::repeat;
 
+    #[test]
+    fn test_series_not() {
+        let s = Series::new("a", &[Some(true), None, Some(false)]);
+        let out = s.not().unwrap();
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(out.bool().unwrap()),
+            &[Some(false), None, Some(true)]
+        );
+
+        let err = Series::new("a", &[1, 2]).not().unwrap_err();
+        assert!(matches!(err, PolarsError::InvalidOperation(_)));
+    }
+
+    #[test]
+    fn test_any_all() {
+        let ca = BooleanChunked::new("a", &[Some(true), None, Some(true)]);
+        assert!(ca.any_ignore_nulls());
+        assert!(ca.all_ignore_nulls());
+        assert_eq!(ca.any_kleene(), Some(true));
+        assert_eq!(ca.all_kleene(), None);
+        // `all` is `false` if there are null values, so is the boolean minimum
+        assert!(ca.any());
+        assert!(!ca.all());
+        assert_eq!(ca.min(), Some(0));
+
+        let ca = BooleanChunked::new("a", &[Some(false), None, Some(false)]);
+        assert!(!ca.any_ignore_nulls());
+        assert!(!ca.all_ignore_nulls());
+        assert_eq!(ca.any_kleene(), None);
+        assert_eq!(ca.all_kleene(), Some(false));
+
+        let ca = BooleanChunked::new("a", &[false, true]);
+        assert!(ca.any_ignore_nulls());
+        assert!(!ca.all_ignore_nulls());
+        assert_eq!(ca.any_kleene(), Some(true));
+        assert_eq!(ca.all_kleene(), Some(false));
+
+        let ca = BooleanChunked::new("a", &[None::<bool>, None]);
+        assert!(!ca.any_ignore_nulls());
+        assert!(ca.all_ignore_nulls());
+        assert_eq!(ca.any_kleene(), None);
+        assert_eq!(ca.all_kleene(), None);
+
+        let ca = BooleanChunked::new("a", &[Some(true), None, Some(false)]).slice(1, 1);
+        assert!(!ca.any_ignore_nulls());
+        assert!(ca.all_ignore_nulls());
+
+        let ca = BooleanChunked::from_slice("a", &[]);
+        assert!(!ca.any_ignore_nulls());
+        assert!(ca.all_ignore_nulls());
+        assert_eq!(ca.any_kleene(), Some(false));
+        assert_eq!(ca.all_kleene(), Some(true));
+
+        let s = Series::new("a", &[Some(true), None]);
+        assert!(s.any().unwrap());
+        assert!(s.all().unwrap());
+        assert!(Series::new("a", &[1, 2]).any().is_err());
+    }
+
     #[test]
     fn test_bitwise_ops() {
         let a = BooleanChunked::new("a", &[true, false, false]);
@@ -1320,4 +1450,79 @@ mod test {
         let out = three.lt_eq(&a);
         assert_eq!(Vec::from(&out), &[Some(false), Some(false), Some(true)]);
     }
+
+    #[test]
+    fn test_equal_element_bool() {
+        use super::ChunkEqualElement;
+
+        // simple linear congruential generator to get reproducible random keys
+        let mut state = 42u64;
+        let mut random_keys = |n: usize| {
+            (0..n)
+                .map(|_| {
+                    state = state
+                        .wrapping_mul(6364136223846793005)
+                        .wrapping_add(1442695040888963407);
+                    match state >> 61 {
+                        0 | 1 => None,
+                        v => Some(v % 2 == 0),
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut a = BooleanChunked::new("a", &random_keys(150));
+        a.append(&BooleanChunked::new("a", &random_keys(70)));
+        let mut b = BooleanChunked::new("b", &random_keys(37));
+        b.append(&BooleanChunked::new("b", &random_keys(113)));
+        let b_s = b.clone().into_series();
+        assert!(a.null_count() > 0 && b.null_count() > 0);
+
+        for i in 0..a.len() {
+            for j in 0..b.len() {
+                let expected = a.get(i) == b.get(j);
+                assert_eq!(unsafe { a.equal_element(i, j, &b_s) }, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bool_series_equal() -> Result<()> {
+        let mut a = Series::new("a", &[Some(true), Some(false), None, Some(true), None]);
+        a.append(&Series::new("a", &[Some(false), Some(true)]))?;
+        let b = Series::new("b", &[true, true, true, false, false, false, true]);
+
+        let out = a.equal(&b)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(&out),
+            &[true, false, false, false, false, true, true].map(Some)
+        );
+        let out = a.not_equal(&b)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[false, true, true, true, true, false, false].map(Some)
+        );
+
+        // a null is not equal to a value
+        let out = a.equal(true)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(&out),
+            &[true, false, false, true, false, false, true].map(Some)
+        );
+        let out = a.equal(false)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[false, true, false, false, false, true, false].map(Some)
+        );
+        let out = a.not_equal(true)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[false, true, true, false, true, true, false].map(Some)
+        );
+        assert_eq!(Vec::from(&b.equal(true)?), Vec::from(b.bool()?));
+        assert!(Series::new("c", &[1, 2]).equal(true).is_err());
+        Ok(())
+    }
 }
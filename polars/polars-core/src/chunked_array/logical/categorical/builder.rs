@@ -1,6 +1,6 @@
 use crate::frame::groupby::hashing::HASHMAP_INIT_SIZE;
 use crate::prelude::*;
-use crate::{datatypes::PlHashMap, use_string_cache, StrHashGlobal};
+use crate::{datatypes::PlHashMap, datatypes::PlHashSet, use_string_cache, StrHashGlobal};
 use ahash::CallHasher;
 use arrow::array::*;
 use hashbrown::hash_map::RawEntryMut;
@@ -255,11 +255,86 @@ impl CategoricalChunkedBuilder {
     }
 }
 
+impl CategoricalChunked {
+    /// Create a [`CategoricalChunked`] from the keys and values of a dictionary array. The
+    /// values become the categories in the same order, so the mapping of the dictionary is
+    /// kept instead of rebuilt in order of occurrence.
+    ///
+    /// Without the global string cache the keys are the physical codes. With the global
+    /// string cache the values are registered in the cache in order and the keys are mapped
+    /// to their global codes.
+    ///
+    /// Returns `None` if the values contain null values or duplicates, which are not valid
+    /// categories, so the categories have to be built with a [`CategoricalChunkedBuilder`]
+    /// instead. The keys must be in bounds of the values.
+    pub(crate) fn from_keys_and_values(
+        name: &str,
+        keys: &PrimitiveArray<u32>,
+        values: &Utf8Array<i64>,
+    ) -> Option<Self> {
+        // a category is a unique, valid string
+        let mut seen = PlHashSet::with_capacity(values.len());
+        if values.null_count() > 0 || !values.values_iter().all(|s| seen.insert(s)) {
+            return None;
+        }
+        let ca = if use_string_cache() {
+            let mut cache = crate::STRING_CACHE.lock_map();
+            let uuid = cache.uuid;
+            let mapping = &mut cache.map;
+            let hb = mapping.hasher().clone();
+
+            // the global code of every value of the dictionary
+            let global_idx = values
+                .values_iter()
+                .map(|s| {
+                    let h = str::get_hash(s, &hb);
+                    let idx = mapping.len() as u32;
+                    let entry = mapping
+                        .raw_entry_mut()
+                        .from_hash(h, |val| (val.hash == h) && val.str == s);
+                    match entry {
+                        RawEntryMut::Occupied(entry) => *entry.get(),
+                        RawEntryMut::Vacant(entry) => {
+                            let key = StrHashGlobal::new(s.into(), h);
+                            entry.insert_with_hasher(h, key, idx, |s| s.hash);
+                            idx
+                        }
+                    }
+                })
+                .collect::<Vec<_>>();
+            drop(cache);
+
+            let map = global_idx
+                .iter()
+                .enumerate()
+                .map(|(local, global)| (*global, local as u32))
+                .collect::<PlHashMap<_, _>>();
+            let iter = keys
+                .into_iter()
+                .map(|opt_k| opt_k.map(|k| global_idx[*k as usize]));
+            let keys = PrimitiveArray::<u32>::from_trusted_len_iter(iter);
+            CategoricalChunked::from_chunks_original(
+                name,
+                vec![Box::new(keys)],
+                RevMapping::Global(map, values.clone(), uuid),
+            )
+        } else {
+            CategoricalChunked::from_chunks_original(
+                name,
+                vec![Box::new(keys.clone())],
+                RevMapping::Local(values.clone()),
+            )
+        };
+        Some(ca)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::chunked_array::categorical::CategoricalChunkedBuilder;
     use crate::prelude::*;
     use crate::{reset_string_cache, toggle_string_cache, SINGLE_LOCK};
+    use arrow::array::{DictionaryArray, PrimitiveArray, Utf8Array};
 
     #[test]
     fn test_categorical_rev() -> Result<()> {
@@ -301,6 +376,43 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_categorical_from_invalid_dictionary() -> Result<()> {
+        let _lock = SINGLE_LOCK.lock();
+        let dictionary = |keys: Vec<Option<u32>>, values: Vec<Option<&str>>| {
+            let arr = DictionaryArray::<u32>::from_data(
+                PrimitiveArray::from(keys),
+                Box::new(Utf8Array::<i32>::from(values)),
+            );
+            Series::try_from(("a", Box::new(arr) as ArrayRef))
+        };
+        for cache in [false, true] {
+            reset_string_cache();
+            toggle_string_cache(cache);
+
+            // duplicate and null values are not used as categories
+            let s = dictionary(
+                vec![Some(0), Some(1), Some(2), None, Some(3)],
+                vec![Some("x"), Some("x"), None, Some("y")],
+            )?;
+            let ca = s.categorical()?;
+            assert_eq!(ca.get_rev_map().len(), 2);
+            assert_eq!(s.null_count(), 2);
+            let utf8 = s.cast(&DataType::Utf8)?;
+            assert_eq!(
+                Vec::from(utf8.utf8()?),
+                &[Some("x"), Some("x"), None, None, Some("y")]
+            );
+
+            assert!(matches!(
+                dictionary(vec![Some(0), Some(2)], vec![Some("x"), Some("y")]),
+                Err(PolarsError::ComputeError(_))
+            ));
+        }
+        toggle_string_cache(false);
+        Ok(())
+    }
+
     #[test]
     fn test_categorical_builder() {
         use crate::{reset_string_cache, toggle_string_cache};
@@ -653,6 +653,55 @@ pub(crate) mod test {
         ChunkedArray::new("a", &[1, 2, 3])
     }
 
+    #[test]
+    fn test_bool_null_count() -> Result<()> {
+        use arrow::bitmap::Bitmap;
+
+        let mut s = Series::new("a", &[Some(true), None, Some(false)]);
+        assert_eq!(s.null_count(), 1);
+        assert!(s.has_validity());
+
+        // every in-place change of the chunks
+        s.append(&Series::new("a", &[None, None]))?;
+        assert_eq!(s.null_count(), 3);
+        s.extend(&Series::new("a", &[None::<bool>]))?;
+        assert_eq!(s.null_count(), 4);
+        let arr = BooleanArray::from(vec![None, Some(true)]);
+        s.append_array(Box::new(arr))?;
+        assert_eq!(s.null_count(), 5);
+        s.shrink_to_fit();
+        assert_eq!(s.null_count(), 5);
+        let nulls = s.bool()?.into_iter().filter(|v| v.is_none()).count();
+        assert_eq!(s.null_count(), nulls);
+
+        let mut ca = BooleanChunked::new("a", &[true, false]);
+        assert_eq!(ca.null_count(), 0);
+        ca.append(&BooleanChunked::new("a", &[None, Some(true)]));
+        assert_eq!(ca.null_count(), 1);
+        ca.extend(&BooleanChunked::new("a", &[None::<bool>]));
+        assert_eq!(ca.null_count(), 2);
+        ca.append_array(Box::new(BooleanArray::from(vec![None])))?;
+        assert_eq!(ca.null_count(), 3);
+
+        let mut df = DataFrame::new(vec![Series::new("a", &[Some(true)])])?;
+        assert!(!df.column("a")?.has_validity());
+        df.vstack_mut(&DataFrame::new(vec![Series::new("a", &[None::<bool>])])?)?;
+        assert_eq!(df.column("a")?.null_count(), 1);
+        df.extend(&DataFrame::new(vec![Series::new("a", &[None::<bool>])])?)?;
+        assert_eq!(df.column("a")?.null_count(), 2);
+        assert!(df.column("a")?.has_validity());
+
+        // a validity bitmap without nulls
+        let arr = BooleanArray::from_data_default(
+            Bitmap::from([true, false]),
+            Some(Bitmap::from([true, true])),
+        );
+        let s = Series::try_from(("a", Box::new(arr) as ArrayRef))?;
+        assert_eq!(s.null_count(), 0);
+        assert!(!s.has_validity());
+        Ok(())
+    }
+
     #[test]
     fn test_sort() {
         let a = Int32Chunked::new("a", &[1, 9, 3, 2]);
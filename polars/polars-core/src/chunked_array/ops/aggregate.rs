@@ -381,7 +381,56 @@ impl ChunkQuantile<String> for Utf8Chunked {}
 impl ChunkQuantile<Series> for ListChunked {}
 #[cfg(feature = "object")]
 impl<T> ChunkQuantile<Series> for ObjectChunked<T> {}
-impl ChunkQuantile<bool> for BooleanChunked {}
+impl ChunkQuantile<f64> for BooleanChunked {
+    /// The quantile of the values as `0.0` and `1.0`, equal to the quantile of the booleans
+    /// cast to an integer type. The sorted array only consists of the nulls, the `false` and
+    /// the `true` values, so the quantile is computed from their counts without sorting.
+    fn quantile(&self, quantile: f64, interpol: QuantileInterpolOptions) -> Result<Option<f64>> {
+        if !(0.0..=1.0).contains(&quantile) {
+            return Err(PolarsError::ComputeError(
+                "quantile should be between 0.0 and 1.0".into(),
+            ));
+        }
+
+        let null_count = self.null_count();
+        let length = self.len();
+
+        if null_count == length {
+            return Ok(None);
+        }
+
+        let n_false = self.count_false();
+        // the value at `idx` of the sorted array, nulls are sorted first
+        let value = |idx: i64| {
+            let idx = idx as usize;
+            if idx < null_count {
+                None
+            } else if idx < null_count + n_false {
+                Some(0.0)
+            } else {
+                Some(1.0)
+            }
+        };
+
+        let (idx, float_idx, top_idx) = quantile_idx(quantile, length, null_count, interpol);
+
+        let opt = match interpol {
+            QuantileInterpolOptions::Midpoint if top_idx != idx => {
+                Some((value(idx).unwrap() + value(idx + 1).unwrap()) / 2.0f64)
+            }
+            QuantileInterpolOptions::Linear if top_idx != idx => {
+                linear_interpol(&[value(idx), value(idx + 1)], idx, float_idx)
+            }
+            _ => value(idx),
+        };
+
+        Ok(opt)
+    }
+
+    fn median(&self) -> Option<f64> {
+        self.quantile(0.5, QuantileInterpolOptions::Linear).unwrap() // unwrap fine since quantile in range
+    }
+}
 
 impl<T> ChunkVar<f64> for ChunkedArray<T>
 where
@@ -453,21 +502,13 @@ impl ChunkVar<bool> for BooleanChunked {}
 
 /// Booleans are casted to 1 or 0.
 impl ChunkAgg<IdxSize> for BooleanChunked {
-    /// Returns `None` if the array is empty or only contains null values.
+    /// Count the `true` values by popcounting the bitmaps, null values are ignored.
+    /// Returns `None` only if the array is empty.
     fn sum(&self) -> Option<IdxSize> {
         if self.is_empty() {
             None
         } else {
-            Some(
-                self.downcast_iter()
-                    .map(|arr| match arr.validity() {
-                        Some(validity) => {
-                            (arr.len() - (validity & arr.values()).null_count()) as IdxSize
-                        }
-                        None => (arr.len() - arr.values().null_count()) as IdxSize,
-                    })
-                    .sum(),
-            )
+            Some(self.count_true() as IdxSize)
         }
     }
 
@@ -679,14 +720,14 @@ impl QuantileAggSeries for Float64Chunked {
 impl QuantileAggSeries for BooleanChunked {
     fn quantile_as_series(
         &self,
-        _quantile: f64,
-        _interpol: QuantileInterpolOptions,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
     ) -> Result<Series> {
-        Ok(Self::full_null(self.name(), 1).into_series())
+        impl_quantile_as_series!(self, quantile, Float64Chunked, quantile, interpol)
     }
 
     fn median_as_series(&self) -> Series {
-        Self::full_null(self.name(), 1).into_series()
+        impl_as_series!(self, median, Float64Chunked)
     }
 }
 impl QuantileAggSeries for ListChunked {
@@ -731,6 +772,8 @@ impl QuantileAggSeries for Utf8Chunked {
 }
 
 impl ChunkAggSeries for BooleanChunked {
+    /// The number of `true` values as a single value of [`IDX_DTYPE`], which is the dtype of
+    /// a grouped `sum` of booleans. Null values are ignored, an empty array sums to null.
     fn sum_as_series(&self) -> Series {
         let v = ChunkAgg::sum(self);
         let mut ca: IdxCa = [v].iter().copied().collect();
@@ -824,6 +867,84 @@ mod test {
     use crate::prelude::*;
     use polars_arrow::prelude::QuantileInterpolOptions;
 
+    #[test]
+    fn test_sum_bool() {
+        // 70k true values would overflow a `u16` accumulator
+        let ca = BooleanChunked::from_iter_values("a", (0..70_001).map(|i| i != 0));
+        assert_eq!(ca.sum(), Some(70_000));
+        let s = ca.into_series();
+        assert_eq!(s.sum::<u64>(), Some(70_000));
+        assert_eq!(s.sum_as_series().dtype(), &IDX_DTYPE);
+
+        let ca = BooleanChunked::new("a", &[None, Some(true), None]);
+        assert_eq!(ca.sum(), Some(1));
+        let ca = BooleanChunked::full_null("a", 2);
+        assert_eq!(ca.sum(), Some(0));
+        let ca = BooleanChunked::from_slice("a", &[]);
+        assert_eq!(ca.sum(), None);
+    }
+
+    #[test]
+    fn test_sum_as_series_bool() -> Result<()> {
+        let s = Series::new("a", &[Some(true), None, Some(false), Some(true)]);
+        let out = s.sum_as_series();
+        assert_eq!(out.name(), "a");
+        assert_eq!(out.dtype(), &IDX_DTYPE);
+        assert_eq!(out.len(), 1);
+        assert_eq!(out.idx()?.get(0), Some(2));
+
+        // the same dtype as a grouped sum
+        let df = df!["g" => [1, 1, 2, 2], "a" => s]?;
+        let grouped = df.groupby(["g"])?.select(["a"]).sum()?;
+        assert_eq!(grouped.column("a_sum")?.dtype(), out.dtype());
+
+        let empty = Series::new("a", &[] as &[bool]);
+        assert_eq!(empty.sum_as_series().dtype(), &IDX_DTYPE);
+        assert_eq!(empty.sum_as_series().null_count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_quantile_bool() -> Result<()> {
+        let ca: BooleanChunked = (0..1000)
+            .map(|i| match i % 7 {
+                0 => None,
+                1 | 2 | 3 => Some(false),
+                _ => Some(true),
+            })
+            .collect();
+        let s = ca.into_series();
+        let cast = s.cast(&DataType::UInt8)?;
+        let interpols = [
+            QuantileInterpolOptions::Nearest,
+            QuantileInterpolOptions::Lower,
+            QuantileInterpolOptions::Higher,
+            QuantileInterpolOptions::Midpoint,
+            QuantileInterpolOptions::Linear,
+        ];
+        for quantile in [0.0, 0.1, 0.25, 0.4, 0.43, 0.5, 0.75, 0.99, 1.0] {
+            for interpol in interpols {
+                let out = s.quantile_as_series(quantile, interpol)?;
+                let expected = cast.quantile_as_series(quantile, interpol)?;
+                assert_eq!(out.dtype(), &DataType::Float64);
+                assert!(
+                    out.series_equal_missing(&expected),
+                    "quantile {} with {:?}",
+                    quantile,
+                    interpol
+                );
+            }
+        }
+        assert!(s
+            .median_as_series()
+            .series_equal_missing(&cast.median_as_series()));
+
+        let s = Series::new("a", &[None, None] as &[Option<bool>]);
+        assert_eq!(s.median_as_series().null_count(), 1);
+        assert!(s.quantile_as_series(1.1, Default::default()).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_var() {
         // validated with numpy
@@ -68,6 +68,26 @@ where
     }
 }
 
+fn det_any(state: &mut bool, v: Option<bool>) -> Option<Option<bool>> {
+    match v {
+        Some(v) => {
+            *state |= v;
+            Some(Some(*state))
+        }
+        None => Some(None),
+    }
+}
+
+fn det_all(state: &mut bool, v: Option<bool>) -> Option<Option<bool>> {
+    match v {
+        Some(v) => {
+            *state &= v;
+            Some(Some(*state))
+        }
+        None => Some(None),
+    }
+}
+
 impl<T> ChunkCumAgg<T> for ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -135,6 +155,42 @@ where
     }
 }
 
+impl BooleanChunked {
+    /// Get an array with the cumulative `any` computed at every element: `true` from the
+    /// first `true` value onward. Null values are skipped and stay null.
+    pub fn cumany(&self, reverse: bool) -> BooleanChunked {
+        let init = false;
+        let mut ca: Self = match reverse {
+            false => self.into_iter().scan(init, det_any).collect_trusted(),
+            true => self
+                .into_iter()
+                .rev()
+                .scan(init, det_any)
+                .collect_reversed(),
+        };
+
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Get an array with the cumulative `all` computed at every element: `false` from the
+    /// first `false` value onward. Null values are skipped and stay null.
+    pub fn cumall(&self, reverse: bool) -> BooleanChunked {
+        let init = true;
+        let mut ca: Self = match reverse {
+            false => self.into_iter().scan(init, det_all).collect_trusted(),
+            true => self
+                .into_iter()
+                .rev()
+                .scan(init, det_all)
+                .collect_reversed(),
+        };
+
+        ca.rename(self.name());
+        ca
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
@@ -171,4 +227,39 @@ mod test {
         let ca = Float32Chunked::new("foo", &[None, Some(1.0), Some(3.0), None, Some(1.0)]);
         let _out = ca.cumsum(false);
     }
+
+    #[test]
+    fn test_cumany_cumall() {
+        let ca = BooleanChunked::new("foo", &[Some(false), None, Some(true), Some(false)]);
+        let out = ca.cumany(false);
+        assert_eq!(out.name(), "foo");
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(false), None, Some(true), Some(true)]
+        );
+        let out = ca.cumany(true);
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(true), None, Some(true), Some(false)]
+        );
+
+        let ca = BooleanChunked::new("foo", &[Some(true), None, Some(false), Some(true)]);
+        let out = ca.cumall(false);
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(true), None, Some(false), Some(false)]
+        );
+        let out = ca.cumall(true);
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(false), None, Some(false), Some(true)]
+        );
+
+        let s = ca.into_series();
+        assert_eq!(
+            Vec::from(s.cumany(false).unwrap().bool().unwrap()),
+            &[Some(true), None, Some(true), Some(true)]
+        );
+        assert!(Series::new("foo", &[1, 2]).cumall(false).is_err());
+    }
 }
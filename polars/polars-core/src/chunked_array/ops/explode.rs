@@ -159,6 +159,39 @@ impl ExplodeByOffsets for BooleanChunked {
         builder.finish().into()
     }
 }
+
+impl BooleanChunked {
+    /// Group the values into a [`ListChunked`] where list `i` holds the values in
+    /// `offsets[i]..offsets[i + 1]`. This is the inverse of exploding a list with the same
+    /// offsets, as long as the list has no empty or null sub-lists.
+    pub fn implode_by_offsets(&self, offsets: &[i64]) -> Result<ListChunked> {
+        let (first, last) = match (offsets.first(), offsets.last()) {
+            (Some(&first), Some(&last)) => (first, last),
+            _ => {
+                return Err(PolarsError::ComputeError(
+                    "offsets must contain at least one value".into(),
+                ))
+            }
+        };
+        if first < 0 || last > self.len() as i64 || offsets.windows(2).any(|w| w[0] > w[1]) {
+            return Err(PolarsError::ComputeError(
+                format!(
+                    "offsets must be increasing and lie within the array of length {}",
+                    self.len()
+                )
+                .into(),
+            ));
+        }
+
+        let mut builder =
+            ListBooleanChunkedBuilder::new(self.name(), offsets.len() - 1, (last - first) as usize);
+        for w in offsets.windows(2) {
+            builder.append(&self.slice(w[0], (w[1] - w[0]) as usize));
+        }
+        Ok(builder.finish())
+    }
+}
+
 impl ExplodeByOffsets for ListChunked {
     fn explode_by_offsets(&self, _offsets: &[i64]) -> Series {
         panic!("cannot explode List of Lists")
@@ -422,6 +455,29 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_implode_by_offsets_bool() -> Result<()> {
+        let mut builder = ListBooleanChunkedBuilder::new("a", 3, 6);
+        builder.append_series(&Series::new("", &[true, false]));
+        builder.append_series(&Series::new("", &[Some(false), None, Some(true)]));
+        builder.append_series(&Series::new("", &[true]));
+        let ca = builder.finish();
+
+        let (exploded, offsets) = ca.explode_and_offsets()?;
+        let out = exploded.bool()?.implode_by_offsets(&offsets)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(out.len(), ca.len());
+        for (l, r) in out.into_iter().zip(ca.into_iter()) {
+            assert!(l.unwrap().series_equal_missing(&r.unwrap()));
+        }
+
+        let ca = BooleanChunked::new("a", &[true, false, true]);
+        assert!(ca.implode_by_offsets(&[]).is_err());
+        assert!(ca.implode_by_offsets(&[0, 2, 1]).is_err());
+        assert!(ca.implode_by_offsets(&[0, 4]).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_explode_empty_list() -> Result<()> {
         let mut builder = get_list_builder(&DataType::Int32, 1, 1, "a")?;
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::utils::bitmap::{bitmap_from_words, bitmap_words};
 use arrow::compute;
 use arrow::types::simd::Simd;
 use num::{Bounded, NumCast, One, Zero};
@@ -174,17 +175,72 @@ where
         .collect_reversed()
 }
 
-fn fill_backward_bool(ca: &BooleanChunked) -> BooleanChunked {
-    ca.into_iter()
-        .rev()
-        .scan(None, |previous, opt_v| match opt_v {
-            Some(value) => {
-                *previous = Some(value);
-                Some(Some(value))
-            }
-            None => Some(*previous),
-        })
-        .collect_reversed()
+/// Fill the nulls with the previous (`forward`) or next non-null value, 64 values at a time.
+///
+/// Within a word the values are propagated over the runs of nulls with a logarithmic number
+/// of shifts. The value at the end of a word, and whether a non-null value was seen at all,
+/// is carried to the next word. Nulls before the first (forward) or after the last
+/// (backward) non-null value stay null.
+fn fill_bool_words(ca: &BooleanChunked, forward: bool) -> BooleanChunked {
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let validity = match arr.validity() {
+        Some(validity) => validity,
+        None => return ca,
+    };
+    let len = arr.len();
+    // the bits after the end are unset, so they are not filled into a backward fill
+    let words = bitmap_words(arr.values())
+        .zip(bitmap_words(validity))
+        .collect::<Vec<_>>();
+    // shift the bits in the direction of the fill, shifting in ones if `ones`
+    let shift = |word: u64, n: u32, ones: bool| {
+        let (shifted, shifted_in) = if forward {
+            (word << n, (1u64 << n) - 1)
+        } else {
+            (word >> n, !(u64::MAX >> n))
+        };
+        if ones {
+            shifted | shifted_in
+        } else {
+            shifted
+        }
+    };
+    let edge = if forward { 63 } else { 0 };
+
+    let mut out_values = vec![0u64; words.len()];
+    let mut out_validity = vec![0u64; words.len()];
+    let mut carry_value = false;
+    let mut carry_valid = false;
+    let order: Box<dyn Iterator<Item = usize>> = if forward {
+        Box::new(0..words.len())
+    } else {
+        Box::new((0..words.len()).rev())
+    };
+    for i in order {
+        let (values, validity) = words[i];
+        let mut filled = values & validity;
+        // after the scan: no non-null value between the start of the word and the bit
+        let mut no_valid = !validity;
+        for n in [1, 2, 4, 8, 16, 32] {
+            filled |= no_valid & shift(filled, n, false);
+            no_valid &= shift(no_valid, n, true);
+        }
+        if carry_value {
+            filled |= no_valid;
+        }
+        let valid = if carry_valid { u64::MAX } else { !no_valid };
+        carry_value = (filled >> edge) & 1 == 1;
+        carry_valid = (valid >> edge) & 1 == 1;
+        out_values[i] = filled;
+        out_validity[i] = valid;
+    }
+
+    let to_bitmap = |words: Vec<u64>| bitmap_from_words(&words, len);
+    let validity = to_bitmap(out_validity);
+    let validity = (validity.null_count() > 0).then(|| validity);
+    let arr = BooleanArray::from_data_default(to_bitmap(out_values), validity);
+    (ca.name(), arr).into()
 }
 
 macro_rules! impl_fill_backward {
@@ -267,14 +323,14 @@ impl ChunkFillNull for BooleanChunked {
             FillNullStrategy::Forward(limit) => {
                 let mut out: Self = match limit {
                     Some(limit) => impl_fill_forward_limit!(self, limit),
-                    None => impl_fill_forward!(self),
+                    None => fill_bool_words(self, true),
                 };
                 out.rename(self.name());
                 Ok(out)
             }
             FillNullStrategy::Backward(limit) => {
                 let mut out: Self = match limit {
-                    None => fill_backward_bool(self),
+                    None => fill_bool_words(self, false),
                     Some(limit) => fill_backward_limit_bool(self, limit),
                 };
                 out.rename(self.name());
@@ -303,7 +359,15 @@ impl ChunkFillNull for BooleanChunked {
 
 impl ChunkFillNullValue<bool> for BooleanChunked {
     fn fill_null_with_values(&self, value: bool) -> Result<Self> {
-        self.set(&self.is_null(), Some(value))
+        // set the bits of the null values with the inverted validity instead of iterating
+        Ok(self.apply_kernel(&|arr| {
+            let values = match (arr.validity(), value) {
+                (Some(validity), true) => arr.values() | &!validity,
+                (Some(validity), false) => arr.values() & validity,
+                (None, _) => arr.values().clone(),
+            };
+            Box::new(BooleanArray::from_data_default(values, None))
+        }))
     }
 }
 
@@ -422,4 +486,93 @@ mod test {
             &[Some(4), Some(4), Some(4), Some(4), Some(4), None]
         );
     }
+
+    #[test]
+    fn test_fill_null_bool_forward_backward() {
+        let mut ca = BooleanChunked::new("a", &[None, None, Some(true), None, Some(false)]);
+        ca.append(&BooleanChunked::new("a", &[None, Some(true), None, None]));
+
+        let filled = ca.fill_null(FillNullStrategy::Forward(None)).unwrap();
+        assert_eq!(filled.name(), "a");
+        assert_eq!(
+            Vec::from(&filled),
+            &[
+                None,
+                None,
+                Some(true),
+                Some(true),
+                Some(false),
+                Some(false),
+                Some(true),
+                Some(true),
+                Some(true)
+            ]
+        );
+        let filled = ca.fill_null(FillNullStrategy::Backward(None)).unwrap();
+        assert_eq!(filled.name(), "a");
+        assert_eq!(
+            Vec::from(&filled),
+            &[
+                Some(true),
+                Some(true),
+                Some(true),
+                Some(false),
+                Some(false),
+                Some(true),
+                Some(true),
+                None,
+                None
+            ]
+        );
+
+        // runs of nulls that cross words, compared with the fill per value
+        let ca: BooleanChunked = (0..200)
+            .map(|i| match i % 70 {
+                0..=9 => None,
+                j if j > 60 => None,
+                j => Some(j % 3 == 0),
+            })
+            .collect();
+        for strategy in [
+            FillNullStrategy::Forward(None),
+            FillNullStrategy::Backward(None),
+        ] {
+            let limited = match strategy {
+                FillNullStrategy::Forward(_) => FillNullStrategy::Forward(Some(IdxSize::MAX)),
+                _ => FillNullStrategy::Backward(Some(IdxSize::MAX)),
+            };
+            let filled = ca.fill_null(strategy).unwrap();
+            let expected = ca.fill_null(limited).unwrap();
+            assert_eq!(Vec::from(&filled), Vec::from(&expected));
+        }
+
+        let ca = BooleanChunked::new("a", &[None, None]);
+        let filled = ca.fill_null(FillNullStrategy::Forward(None)).unwrap();
+        assert_eq!(filled.null_count(), 2);
+    }
+
+    #[test]
+    fn test_fill_null_bool_with_value() {
+        let mut ca = BooleanChunked::new("a", &[Some(true), None, Some(false), None]);
+        ca.append(&BooleanChunked::new("a", &[None, Some(true), Some(false)]));
+
+        let filled = ca.fill_null_with_values(true).unwrap();
+        assert_eq!(filled.name(), "a");
+        assert_eq!(filled.null_count(), 0);
+        assert_eq!(
+            Vec::from(&filled),
+            &[true, true, false, true, true, true, false].map(Some)
+        );
+
+        let s = ca.into_series();
+        let filled = s.fill_null_with_value(false).unwrap();
+        assert_eq!(filled.null_count(), 0);
+        assert_eq!(
+            Vec::from(filled.bool().unwrap()),
+            &[true, false, false, false, false, true, false].map(Some)
+        );
+        assert!(Series::new("a", &[1, 2])
+            .fill_null_with_value(true)
+            .is_err());
+    }
 }
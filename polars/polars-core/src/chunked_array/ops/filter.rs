@@ -2,8 +2,9 @@
 use crate::chunked_array::object::builder::ObjectChunkedBuilder;
 use crate::prelude::*;
 use crate::utils::align_chunks_binary;
-#[cfg(feature = "object")]
-use arrow::array::Array;
+use crate::utils::bitmap::bitmap_words;
+use arrow::array::{Array, BooleanArray};
+use arrow::bitmap::{Bitmap, MutableBitmap};
 use arrow::compute::filter::filter as filter_fn;
 
 macro_rules! check_filter_len {
@@ -64,12 +65,52 @@ impl ChunkFilter<BooleanType> for BooleanChunked {
         let chunks = left
             .downcast_iter()
             .zip(filter.downcast_iter())
-            .map(|(left, mask)| filter_fn(left, mask).unwrap())
+            .map(|(left, mask)| {
+                if mask.null_count() == 0 {
+                    Box::new(filter_boolean_array(left, mask.values())) as ArrayRef
+                } else {
+                    filter_fn(left, mask).unwrap()
+                }
+            })
             .collect::<Vec<_>>();
         Ok(self.copy_with_chunks(chunks))
     }
 }
 
+/// Append the bits of `values` at the positions of the set bits in `mask`.
+#[inline]
+fn push_selected_bits(out: &mut MutableBitmap, values: u64, mut mask: u64) {
+    if mask == u64::MAX {
+        out.extend_from_slice(&values.to_le_bytes(), 0, 64);
+        return;
+    }
+    while mask != 0 {
+        out.push((values >> mask.trailing_zeros()) & 1 == 1);
+        // clear the lowest set bit
+        mask &= mask - 1;
+    }
+}
+
+/// Gather the bits of `values` where `mask` is set, processing 64 bits at a time.
+fn filter_bitmap(values: &Bitmap, mask: &Bitmap, out_len: usize) -> Bitmap {
+    let mut out = MutableBitmap::with_capacity(out_len);
+    for (values, mask) in bitmap_words(values).zip(bitmap_words(mask)) {
+        push_selected_bits(&mut out, values, mask);
+    }
+    out.into()
+}
+
+/// Filter a boolean array by a mask without nulls. The values and the validity are
+/// gathered directly from the bitmaps instead of per element.
+fn filter_boolean_array(arr: &BooleanArray, mask: &Bitmap) -> BooleanArray {
+    let out_len = mask.len() - mask.null_count();
+    let values = filter_bitmap(arr.values(), mask, out_len);
+    let validity = arr
+        .validity()
+        .map(|validity| filter_bitmap(validity, mask, out_len));
+    BooleanArray::from_data_default(values, validity)
+}
+
 impl ChunkFilter<Utf8Type> for Utf8Chunked {
     fn filter(&self, filter: &BooleanChunked) -> Result<ChunkedArray<Utf8Type>> {
         // broadcast
@@ -157,3 +198,64 @@ where
         Ok(builder.finish())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    fn filter_by_iter(ca: &BooleanChunked, mask: &BooleanChunked) -> Vec<Option<bool>> {
+        ca.into_iter()
+            .zip(mask.into_iter())
+            .filter(|(_, m)| *m == Some(true))
+            .map(|(v, _)| v)
+            .collect()
+    }
+
+    #[test]
+    fn test_filter_bool() -> Result<()> {
+        // a pseudo random sequence, so the words of the mask are mixed
+        let mut state = 42u64;
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            state >> 33
+        };
+        let values: Vec<Option<bool>> = (0..1000)
+            .map(|_| match next() % 3 {
+                0 => None,
+                v => Some(v == 1),
+            })
+            .collect();
+        let mask_values: Vec<bool> = (0..1000).map(|i| i < 130 || next() % 2 == 0).collect();
+
+        let ca = BooleanChunked::new("a", &values);
+        let mask = BooleanChunked::new("mask", &mask_values);
+        let out = ca.filter(&mask)?;
+        assert_eq!(Vec::from(&out), filter_by_iter(&ca, &mask));
+
+        // misaligned chunks and a mask with an offset
+        let mut ca = BooleanChunked::new("a", &values[..333]);
+        ca.append(&BooleanChunked::new("a", &values[333..]));
+        let mask = BooleanChunked::new("mask", &[&[true; 3][..], mask_values.as_slice()].concat())
+            .slice(3, 1000);
+        let out = ca.filter(&mask)?;
+        assert_eq!(Vec::from(&out), filter_by_iter(&ca, &mask));
+
+        // a mask with nulls takes the generic path
+        let mask = BooleanChunked::new("mask", &[Some(true), None, Some(false), Some(true)]);
+        let ca = BooleanChunked::new("a", &[Some(true), Some(true), None, None]);
+        assert_eq!(Vec::from(&ca.filter(&mask)?), &[Some(true), None]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_bool_large() -> Result<()> {
+        let n = 1_000_000;
+        let ca: BooleanChunked = (0..n).map(|i| i % 3 == 0).collect();
+        let mask: BooleanChunked = (0..n).map(|i| i % 7 != 0).collect();
+
+        let out = ca.filter(&mask)?;
+        assert_eq!(out.len(), mask.sum().unwrap() as usize);
+        assert_eq!(Vec::from(&out), filter_by_iter(&ca, &mask));
+        Ok(())
+    }
+}
@@ -174,6 +174,32 @@ impl_interpolate!(Int64Type, signed_interp);
 impl_interpolate!(Float32Type, signed_interp);
 impl_interpolate!(Float64Type, signed_interp);
 
+/// Booleans cannot be interpolated linearly, so a null gap between two values is filled with
+/// the nearest value. In the middle of a gap with an odd length the previous value is used.
+/// Like the numeric interpolation, leading and trailing nulls are not filled.
+impl Interpolate for BooleanChunked {
+    fn interpolate(&self) -> Self {
+        if !self.has_validity() || self.null_count() == self.len() {
+            return self.clone();
+        }
+
+        let mut values = self.into_iter().collect::<Vec<_>>();
+        let mut prev: Option<(usize, bool)> = None;
+        for i in 0..values.len() {
+            if let Some(high) = values[i] {
+                if let Some((low_idx, low)) = prev {
+                    for j in low_idx + 1..i {
+                        let nearest_is_low = j - low_idx <= i - j;
+                        values[j] = Some(if nearest_is_low { low } else { high });
+                    }
+                }
+                prev = Some((i, high));
+            }
+        }
+        BooleanChunked::new(self.name(), &values)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -202,6 +228,49 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_interpolate_bool() {
+        let ca = BooleanChunked::new(
+            "a",
+            &[
+                None,
+                Some(true),
+                None,
+                None,
+                None,
+                Some(false),
+                None,
+                None,
+                Some(true),
+                None,
+                Some(true),
+                None,
+            ],
+        );
+        let out = ca.interpolate();
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(&out),
+            &[
+                None,
+                Some(true),
+                Some(true),
+                Some(true),
+                Some(false),
+                Some(false),
+                Some(false),
+                Some(true),
+                Some(true),
+                Some(true),
+                Some(true),
+                None
+            ]
+        );
+
+        let s = ca.into_series().interpolate();
+        assert_eq!(s.null_count(), 2);
+    }
+
     #[test]
     fn test_interpolate_decreasing_unsigned() {
         let ca = UInt32Chunked::new("", &[Some(4), None, None, Some(1)]);
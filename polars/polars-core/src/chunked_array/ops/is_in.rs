@@ -1,5 +1,6 @@
 use crate::prelude::*;
 use crate::utils::{get_supertype, CustomIterTools};
+use arrow::bitmap::{Bitmap, MutableBitmap};
 use hashbrown::hash_set::HashSet;
 use std::hash::Hash;
 
@@ -217,6 +218,29 @@ impl IsIn for BooleanChunked {
                 ca.rename(self.name());
                 Ok(ca)
             }
+            DataType::Boolean => {
+                // there are only three distinct values, so the set is a flag per value
+                let other = other.bool()?;
+                let has_true = other.count_true() > 0;
+                let has_false = other.count_false() > 0;
+                let has_null = other.null_count() > 0;
+
+                Ok(self.apply_kernel(&|arr| {
+                    let values = arr.values();
+                    let out: Bitmap = match (has_true, has_false) {
+                        (true, true) => MutableBitmap::from_len_set(arr.len()).into(),
+                        (true, false) => values.clone(),
+                        (false, true) => !values,
+                        (false, false) => MutableBitmap::from_len_zeroed(arr.len()).into(),
+                    };
+                    let out = match arr.validity() {
+                        Some(validity) if has_null => &(&out & validity) | &!validity,
+                        Some(validity) => &out & validity,
+                        None => out,
+                    };
+                    Box::new(BooleanArray::from_data_default(out, None))
+                }))
+            }
             _ => Err(PolarsError::SchemaMisMatch(
                 format!(
                     "cannot do is_in operation with left a dtype: {:?} and right a dtype {:?}",
@@ -322,4 +346,33 @@ mod test {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_is_in_bool() -> Result<()> {
+        let mut a = BooleanChunked::new("a", &[Some(true), Some(false), None]);
+        a.append(&BooleanChunked::new("a", &[Some(false), Some(true)]));
+
+        let check = |set: &[Option<bool>]| -> Result<()> {
+            let out = a.is_in(&Series::new("b", set))?;
+            let expected = a
+                .into_iter()
+                .map(|v| Some(set.contains(&v)))
+                .collect::<Vec<_>>();
+            assert_eq!(Vec::from(&out), expected);
+            assert_eq!(out.name(), "a");
+            Ok(())
+        };
+        check(&[Some(true)])?;
+        check(&[Some(false)])?;
+        check(&[Some(true), Some(false)])?;
+        check(&[Some(true), None])?;
+        check(&[])?;
+
+        let out = a.is_in(&Series::new("b", &[true]))?;
+        assert_eq!(
+            Vec::from(&out),
+            &[true, false, false, false, true].map(Some)
+        );
+        Ok(())
+    }
 }
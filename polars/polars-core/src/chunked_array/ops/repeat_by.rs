@@ -1,6 +1,7 @@
 use super::RepeatBy;
 use crate::prelude::*;
 use arrow::array::ListArray;
+use arrow::bitmap::MutableBitmap;
 use polars_arrow::array::ListFromIter;
 
 type LargeListArray = ListArray<i64>;
@@ -45,6 +46,41 @@ impl RepeatBy for BooleanChunked {
         )
     }
 }
+
+impl BooleanChunked {
+    /// Repeat every value `n` times, which is the same as [`RepeatBy::repeat_by`] with all
+    /// counts equal to `n`. The offsets follow from `n`, so no counts have to be allocated.
+    pub fn repeat_by_scalar(&self, n: usize) -> ListChunked {
+        let len = self.len();
+        let offsets = (0..=len as i64).map(|i| i * n as i64).collect::<Vec<_>>();
+        let mut values = MutableBitmap::with_capacity(len * n);
+        let mut validity = self
+            .has_validity()
+            .then(|| MutableBitmap::with_capacity(len * n));
+        for arr in self.downcast_iter() {
+            for opt_v in arr.iter() {
+                values.extend_constant(n, opt_v.unwrap_or(false));
+                if let Some(validity) = &mut validity {
+                    validity.extend_constant(n, opt_v.is_some());
+                }
+            }
+        }
+        let values = BooleanArray::from_data_default(values.into(), validity.map(|v| v.into()));
+        let data_type = LargeListArray::default_datatype(ArrowDataType::Boolean);
+
+        // Safety:
+        // the offsets are increasing and end at the length of the values
+        let arr = unsafe {
+            LargeListArray::new_unchecked(data_type, offsets.into(), Box::new(values), None)
+        };
+        let mut ca = ListChunked::from_chunks(self.name(), vec![Box::new(arr)]);
+        if n > 0 {
+            ca.set_fast_explode();
+        }
+        ca
+    }
+}
+
 impl RepeatBy for Utf8Chunked {
     fn repeat_by(&self, by: &IdxCa) -> ListChunked {
         let iter = self
@@ -62,3 +98,36 @@ impl RepeatBy for Utf8Chunked {
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_repeat_by_scalar_bool() -> Result<()> {
+        let ca = BooleanChunked::new("a", &[Some(true), None, Some(false)]);
+        let out = ca.repeat_by_scalar(2);
+        assert_eq!(out.name(), "a");
+        assert_eq!(out.len(), 3);
+        let by = IdxCa::new("by", &[2, 2, 2]);
+        for (a, b) in out.into_iter().zip(ca.repeat_by(&by).into_iter()) {
+            assert!(a.unwrap().series_equal_missing(&b.unwrap()));
+        }
+
+        let lists = out.into_iter().collect::<Vec<_>>();
+        assert_eq!(
+            Vec::from(lists[0].as_ref().unwrap().bool()?),
+            &[Some(true), Some(true)]
+        );
+        assert_eq!(lists[1].as_ref().unwrap().null_count(), 2);
+        assert_eq!(
+            Vec::from(lists[2].as_ref().unwrap().bool()?),
+            &[Some(false), Some(false)]
+        );
+
+        let s = ca.into_series();
+        assert_eq!(s.repeat_by_scalar(0)?.get(0).unwrap().len(), 0);
+        assert!(Series::new("a", &[1, 2]).repeat_by_scalar(2).is_err());
+        Ok(())
+    }
+}
@@ -1,5 +1,8 @@
 use crate::prelude::*;
+use crate::utils::bitmap::bitmap_words;
 use crate::utils::{CustomIterTools, NoNull};
+use arrow::array::BooleanArray;
+use arrow::bitmap::{Bitmap, MutableBitmap};
 
 impl<T> ChunkReverse<T> for ChunkedArray<T>
 where
@@ -30,7 +33,42 @@ macro_rules! impl_reverse {
     };
 }
 
-impl_reverse!(BooleanType, BooleanChunked);
+/// Reverse the bits of `bitmap`, processing 64 bits at a time.
+fn reverse_bitmap(bitmap: &Bitmap) -> Bitmap {
+    let len = bitmap.len();
+    let words = bitmap_words(bitmap).collect::<Vec<_>>();
+    // the words are reversed as a whole, so the bits after the end of the bitmap are in front
+    // and skipped
+    let padding = words.len() * 64 - len;
+    let bytes = words
+        .iter()
+        .rev()
+        .flat_map(|word| word.reverse_bits().to_le_bytes())
+        .collect::<Vec<_>>();
+    let mut out = MutableBitmap::with_capacity(len);
+    if len > 0 {
+        out.extend_from_slice(&bytes, padding, len);
+    }
+    out.into()
+}
+
+impl ChunkReverse<BooleanType> for BooleanChunked {
+    fn reverse(&self) -> Self {
+        // a single chunk is reversed word by word, the bits of multiple chunks are not aligned
+        if self.chunks.len() == 1 {
+            let arr = self.downcast_iter().next().unwrap();
+            let values = reverse_bitmap(arr.values());
+            let validity = arr.validity().map(reverse_bitmap);
+            let arr = BooleanArray::from_data_default(values, validity);
+            Self::from_chunks(self.name(), vec![Box::new(arr) as ArrayRef])
+        } else {
+            let mut ca: Self = self.into_iter().rev().collect_trusted();
+            ca.rename(self.name());
+            ca
+        }
+    }
+}
+
 impl_reverse!(Utf8Type, Utf8Chunked);
 impl_reverse!(ListType, ListChunked);
 
@@ -42,3 +80,41 @@ impl<T: PolarsObject> ChunkReverse<ObjectType<T>> for ObjectChunked<T> {
         unsafe { self.take_unchecked((0..self.len()).rev().into()) }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::utils::CustomIterTools;
+
+    #[test]
+    fn test_reverse_bool() {
+        // crosses the boundary of a 64 bit word
+        let ca: BooleanChunked = (0..65)
+            .map(|i| match i % 5 {
+                0 => None,
+                1 | 3 => Some(true),
+                _ => Some(false),
+            })
+            .collect();
+        let check = |ca: &BooleanChunked| {
+            let mut expected: BooleanChunked = ca.into_iter().rev().collect_trusted();
+            expected.rename(ca.name());
+            let out = ca.reverse();
+            assert_eq!(out.name(), ca.name());
+            assert_eq!(out.null_count(), ca.null_count());
+            assert!(out
+                .into_series()
+                .series_equal_missing(&expected.into_series()));
+        };
+        check(&ca);
+        // a bitmap with an offset
+        check(&ca.slice(3, 61));
+        check(&ca.slice(0, 0));
+        // multiple chunks
+        let mut chunked = ca.clone();
+        chunked.append(&ca.slice(7, 20));
+        check(&chunked);
+        // without nulls
+        check(&BooleanChunked::new("a", &[true; 65]));
+    }
+}
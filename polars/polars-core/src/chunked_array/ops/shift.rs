@@ -161,4 +161,37 @@ mod test {
             &[Some("b"), Some("c"), None]
         );
     }
+
+    #[test]
+    fn test_shift_and_fill_bool() -> Result<()> {
+        let s = Series::new("a", &[Some(true), None, Some(true)]);
+
+        let shifted = s.shift_and_fill_bool(1, false)?;
+        assert_eq!(Vec::from(shifted.bool()?), &[Some(false), Some(true), None]);
+        let shifted = s.shift_and_fill_bool(2, true)?;
+        assert_eq!(
+            Vec::from(shifted.bool()?),
+            &[Some(true), Some(true), Some(true)]
+        );
+        let shifted = s.shift_and_fill_bool(4, false)?;
+        assert_eq!(
+            Vec::from(shifted.bool()?),
+            &[Some(false), Some(false), Some(false)]
+        );
+
+        let shifted = s.shift_and_fill_bool(-1, false)?;
+        assert_eq!(Vec::from(shifted.bool()?), &[None, Some(true), Some(false)]);
+        let shifted = s.shift_and_fill_bool(-2, false)?;
+        assert_eq!(
+            Vec::from(shifted.bool()?),
+            &[Some(true), Some(false), Some(false)]
+        );
+
+        let shifted = s.shift_and_fill_bool(0, false)?;
+        assert!(shifted.series_equal_missing(&s));
+
+        let err = Series::new("a", &[1, 2]).shift_and_fill_bool(1, false);
+        assert!(matches!(err, Err(PolarsError::InvalidOperation(_))));
+        Ok(())
+    }
 }
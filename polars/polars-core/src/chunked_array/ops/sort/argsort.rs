@@ -75,3 +75,47 @@ where
     let arr = IdxArr::from_data_default(Buffer::from(idx), None);
     IdxCa::from_chunks(name, vec![Box::new(arr)])
 }
+
+/// Counting sort of the indices of a boolean array. The indices are collected in a null,
+/// `false` and `true` bucket in one pass, which gives the same order as the stable
+/// [`argsort`].
+pub(super) fn argsort_bool(ca: &BooleanChunked, options: SortOptions) -> IdxCa {
+    let len = ca.len();
+    let null_count = ca.null_count();
+    let n_true = ca.count_true();
+    let mut nulls_idx = Vec::with_capacity(null_count);
+    let mut false_idx = Vec::with_capacity(len - null_count - n_true);
+    let mut true_idx = Vec::with_capacity(n_true);
+
+    let mut count: IdxSize = 0;
+    for arr in ca.downcast_iter() {
+        for v in arr.iter() {
+            match v {
+                Some(true) => true_idx.push(count),
+                Some(false) => false_idx.push(count),
+                None => nulls_idx.push(count),
+            }
+            count += 1;
+        }
+    }
+
+    let (first, second) = if options.descending {
+        (true_idx, false_idx)
+    } else {
+        (false_idx, true_idx)
+    };
+    let mut idx = Vec::with_capacity(len);
+    // same as the generic argsort, nulls that are sorted last are in reverse order
+    if options.descending || options.nulls_last {
+        idx.extend(first);
+        idx.extend(second);
+        idx.extend(nulls_idx.into_iter().rev());
+    } else {
+        idx.extend(nulls_idx);
+        idx.extend(first);
+        idx.extend(second);
+    }
+
+    let arr = IdxArr::from_data_default(Buffer::from(idx), None);
+    IdxCa::from_chunks(ca.name(), vec![Box::new(arr)])
+}
@@ -518,20 +518,43 @@ impl ChunkSort<Utf8Type> for Utf8Chunked {
 impl ChunkSort<BooleanType> for BooleanChunked {
     fn sort_with(&self, options: SortOptions) -> ChunkedArray<BooleanType> {
         sort_with_fast_path!(self, options);
-        assert!(
-            !options.nulls_last,
-            "null last not yet supported for bool dtype"
-        );
-        let mut vals = self.into_iter().collect::<Vec<_>>();
+        // there are only three distinct values, so the sorted array consists of three runs
+        // that can be set directly from the counts
+        let len = self.len();
+        let null_count = self.null_count();
+        let n_true = self.count_true();
+        let n_false = len - null_count - n_true;
 
+        let mut values = MutableBitmap::with_capacity(len);
+        if !options.nulls_last {
+            values.extend_constant(null_count, false);
+        }
         if options.descending {
-            vals.sort_by(|a, b| b.cmp(a))
+            values.extend_constant(n_true, true);
+            values.extend_constant(n_false, false);
         } else {
-            vals.sort()
+            values.extend_constant(n_false, false);
+            values.extend_constant(n_true, true);
+        }
+        if options.nulls_last {
+            values.extend_constant(null_count, false);
         }
 
-        let mut ca: BooleanChunked = vals.into_iter().collect_trusted();
-        ca.rename(self.name());
+        let validity = (null_count > 0).then(|| {
+            let mut validity = MutableBitmap::with_capacity(len);
+            if options.nulls_last {
+                validity.extend_constant(len - null_count, true);
+                validity.extend_constant(null_count, false);
+            } else {
+                validity.extend_constant(null_count, false);
+                validity.extend_constant(len - null_count, true);
+            }
+            validity.into()
+        });
+
+        let arr = BooleanArray::from_data_default(values.into(), validity);
+        let mut ca = BooleanChunked::from_chunks(self.name(), vec![Box::new(arr)]);
+        ca.set_sorted(options.descending);
         ca
     }
 
@@ -543,13 +566,7 @@ impl ChunkSort<BooleanType> for BooleanChunked {
     }
 
     fn argsort(&self, options: SortOptions) -> IdxCa {
-        argsort::argsort(
-            self.name(),
-            self.downcast_iter().map(|arr| arr.iter()),
-            options,
-            self.null_count(),
-            self.len(),
-        )
+        argsort::argsort_bool(self, options)
     }
 }
 
@@ -631,6 +648,81 @@ mod test {
         assert_eq!(idx, expected);
     }
 
+    #[test]
+    fn test_sort_bool() {
+        let mut ca: BooleanChunked = (0..1000)
+            .map(|i| match i % 5 {
+                0 => None,
+                1 | 3 => Some(true),
+                _ => Some(false),
+            })
+            .collect();
+        ca.append(&BooleanChunked::new("", &[Some(false), None, Some(true)]));
+
+        for descending in [false, true] {
+            for nulls_last in [false, true] {
+                let out = ca.sort_with(SortOptions {
+                    descending,
+                    nulls_last,
+                });
+                let mut values = ca.into_iter().flatten().collect::<Vec<_>>();
+                values.sort_unstable();
+                if descending {
+                    values.reverse()
+                }
+                let nulls = std::iter::repeat(None).take(ca.null_count());
+                let values = values.into_iter().map(Some);
+                let expected: Vec<_> = if nulls_last {
+                    values.chain(nulls).collect()
+                } else {
+                    nulls.chain(values).collect()
+                };
+                assert_eq!(Vec::from(&out), expected);
+                assert_eq!(out.null_count(), ca.null_count());
+            }
+        }
+
+        let ca = BooleanChunked::new("a", &[true, false, true]);
+        let out = ca.sort(true);
+        assert_eq!(Vec::from(&out), &[Some(true), Some(true), Some(false)]);
+        assert_eq!(out.name(), "a");
+        assert!(out.is_sorted_reverse());
+    }
+
+    #[test]
+    fn test_argsort_bool() {
+        let mut ca: BooleanChunked = (0..10_000)
+            .map(|i| match i % 7 {
+                0 | 3 => None,
+                1 | 4 | 5 => Some(true),
+                _ => Some(false),
+            })
+            .collect();
+        ca.append(&BooleanChunked::new("", &[Some(false), None, Some(true)]));
+
+        for descending in [false, true] {
+            for nulls_last in [false, true] {
+                let options = SortOptions {
+                    descending,
+                    nulls_last,
+                };
+                let generic = super::argsort::argsort(
+                    ca.name(),
+                    ca.downcast_iter().map(|arr| arr.iter()),
+                    options,
+                    ca.null_count(),
+                    ca.len(),
+                );
+                assert_eq!(Vec::from(&ca.argsort(options)), Vec::from(&generic));
+            }
+        }
+
+        let ca = BooleanChunked::new("a", &[Some(true), None, Some(false), Some(true)]);
+        let idx = ca.argsort(SortOptions::default());
+        assert_eq!(idx.cont_slice().unwrap(), &[1, 2, 0, 3]);
+        assert_eq!(idx.name(), "a");
+    }
+
     #[test]
     fn test_sort() {
         let a = Int32Chunked::new(
@@ -29,6 +29,29 @@ impl ChunkTakeEvery<BooleanType> for BooleanChunked {
     }
 }
 
+impl BooleanChunked {
+    /// Take every `n`th value, starting at index `offset`. An `offset` past the end gives an
+    /// empty array. The values are skipped while iterating, no indices are materialized.
+    pub fn take_every_offset(&self, n: usize, offset: usize) -> Result<Self> {
+        if n == 0 {
+            return Err(PolarsError::ComputeError(
+                "take_every_offset requires n to be larger than 0".into(),
+            ));
+        }
+        if offset >= self.len() {
+            return Ok(self.slice(0, 0));
+        }
+        let sliced = self.slice(offset as i64, self.len() - offset);
+        let mut ca: Self = if !sliced.has_validity() {
+            sliced.into_no_null_iter().step_by(n).collect()
+        } else {
+            sliced.into_iter().step_by(n).collect()
+        };
+        ca.rename(self.name());
+        Ok(ca)
+    }
+}
+
 impl ChunkTakeEvery<Utf8Type> for Utf8Chunked {
     fn take_every(&self, n: usize) -> Utf8Chunked {
         let mut ca: Self = if !self.has_validity() {
@@ -59,3 +82,32 @@ impl<T> ChunkTakeEvery<ObjectType<T>> for ObjectChunked<T> {
         todo!()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_take_every_offset_bool() -> Result<()> {
+        let ca: BooleanChunked = (0..10)
+            .map(|i| if i == 4 { None } else { Some(i % 2 == 0) })
+            .collect();
+        let out = ca.take_every_offset(3, 1)?;
+        assert_eq!(Vec::from(&out), &[Some(false), None, Some(false)]);
+        assert_eq!(
+            Vec::from(&ca.take_every_offset(1, 0)?),
+            Vec::from(&ca.take_every(1))
+        );
+        assert_eq!(ca.take_every_offset(3, 10)?.len(), 0);
+        assert_eq!(ca.take_every_offset(3, 9)?.len(), 1);
+        assert!(ca.take_every_offset(0, 1).is_err());
+
+        let s = ca.into_series();
+        let out = s.take_every_offset(3, 1)?;
+        assert_eq!(Vec::from(out.bool()?), &[Some(false), None, Some(false)]);
+        assert!(Series::new("a", &[1, 2, 3])
+            .take_every_offset(2, 1)
+            .is_err());
+        Ok(())
+    }
+}
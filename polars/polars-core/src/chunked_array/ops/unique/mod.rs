@@ -8,6 +8,9 @@ use crate::frame::groupby::GroupsProxy;
 #[cfg(feature = "mode")]
 use crate::frame::groupby::IntoGroupsProxy;
 use crate::prelude::*;
+use crate::utils::bitmap::bitmap_words;
+#[cfg(feature = "mode")]
+use std::cmp::Ordering;
 use std::hash::Hash;
 
 fn finish_is_unique_helper(
@@ -239,18 +242,56 @@ impl ChunkUnique<Utf8Type> for Utf8Chunked {
     }
 }
 
-impl ChunkUnique<BooleanType> for BooleanChunked {
-    fn unique(&self) -> Result<Self> {
-        // can be None, Some(true), Some(false)
-        let mut unique = Vec::with_capacity(3);
-        for v in self {
-            if unique.len() == 3 {
-                break;
+/// Find the index of the first `true`, `false` and null value, in that order. The values
+/// are scanned 64 at a time and the scan stops once every state that can occur is found.
+fn first_occurrences(ca: &BooleanChunked) -> [Option<usize>; 3] {
+    let has_nulls = ca.null_count() > 0;
+    let mut first = [None; 3];
+    let mut offset = 0;
+    for arr in ca.downcast_iter() {
+        let validity_words: Box<dyn Iterator<Item = u64>> = match arr.validity() {
+            Some(validity) => Box::new(bitmap_words(validity)),
+            None => Box::new(std::iter::repeat(u64::MAX)),
+        };
+        let words = bitmap_words(arr.values()).zip(validity_words);
+        for (i, (values, validity)) in words.enumerate() {
+            let base = i * 64;
+            let remaining = arr.len() - base;
+            let in_bounds = if remaining >= 64 {
+                u64::MAX
+            } else {
+                u64::MAX >> (64 - remaining)
+            };
+            let states = [values & validity, !values & validity, !validity];
+            for (first, word) in first.iter_mut().zip(states) {
+                let word = word & in_bounds;
+                if first.is_none() && word != 0 {
+                    *first = Some(offset + base + word.trailing_zeros() as usize);
+                }
             }
-            if !unique.contains(&v) {
-                unique.push(v)
+            if first[0].is_some() && first[1].is_some() && (!has_nulls || first[2].is_some()) {
+                return first;
             }
         }
+        offset += arr.len();
+    }
+    first
+}
+
+impl ChunkUnique<BooleanType> for BooleanChunked {
+    /// The unique values are returned in the order of their first occurrence.
+    fn unique(&self) -> Result<Self> {
+        // can be Some(true), Some(false), None
+        let mut found = [Some(true), Some(false), None]
+            .into_iter()
+            .zip(first_occurrences(self))
+            .filter_map(|(value, idx)| idx.map(|idx| (idx, value)))
+            .collect::<Vec<_>>();
+        found.sort_unstable_by_key(|(idx, _)| *idx);
+        let unique = found
+            .into_iter()
+            .map(|(_, value)| value)
+            .collect::<Vec<_>>();
         Ok(ChunkedArray::new(self.name(), &unique))
     }
 
@@ -258,12 +299,38 @@ impl ChunkUnique<BooleanType> for BooleanChunked {
         Ok(IdxCa::from_vec(self.name(), arg_unique_ca!(self)))
     }
 
+    /// A null value counts as a distinct value, so the result is at most 3.
+    fn n_unique(&self) -> Result<usize> {
+        Ok(first_occurrences(self)
+            .iter()
+            .filter(|idx| idx.is_some())
+            .count())
+    }
+
     fn is_unique(&self) -> Result<BooleanChunked> {
         is_unique_duplicated!(self, false)
     }
     fn is_duplicated(&self) -> Result<BooleanChunked> {
         is_unique_duplicated!(self, true)
     }
+
+    /// Null values are ignored, so an empty or all-null array returns an empty array.
+    /// On a tie both values are returned, sorted as `[false, true]`.
+    #[cfg(feature = "mode")]
+    fn mode(&self) -> Result<Self> {
+        let n_true = self.count_true();
+        let n_false = self.count_false();
+        let values: &[bool] = if n_true + n_false == 0 {
+            &[]
+        } else {
+            match n_true.cmp(&n_false) {
+                Ordering::Less => &[false],
+                Ordering::Greater => &[true],
+                Ordering::Equal => &[false, true],
+            }
+        };
+        Ok(BooleanChunked::from_slice(self.name(), values))
+    }
 }
 
 impl ChunkUnique<Float32Type> for Float32Chunked {
@@ -470,4 +537,87 @@ mod test {
         let result3 = Vec::from(&ca3.mode().unwrap());
         assert_eq!(result3, &[]);
     }
+
+    #[test]
+    #[cfg(feature = "mode")]
+    fn mode_bool() {
+        let ca = BooleanChunked::new("a", &[Some(true), None, Some(false), Some(true)]);
+        assert_eq!(Vec::from(&ca.mode().unwrap()), &[Some(true)]);
+
+        // ties return both values, sorted
+        let ca = BooleanChunked::new("a", &[Some(true), Some(false), None, None]);
+        assert_eq!(Vec::from(&ca.mode().unwrap()), &[Some(false), Some(true)]);
+
+        // all null
+        let ca = BooleanChunked::full_null("a", 3);
+        let out = ca.mode().unwrap();
+        assert_eq!(out.len(), 0);
+        assert_eq!(out.name(), "a");
+
+        let s = Series::new("a", &[None::<bool>, None]);
+        let out = s.mode().unwrap();
+        assert_eq!(out.dtype(), &DataType::Boolean);
+        assert_eq!(out.len(), 0);
+    }
+
+    #[test]
+    fn unique_bool_large() {
+        let n = 1_000_000;
+        let naive = |ca: &BooleanChunked| {
+            let mut unique = vec![];
+            for v in ca {
+                if !unique.contains(&v) {
+                    unique.push(v)
+                }
+            }
+            unique
+        };
+
+        let values = (0..n)
+            .map(|i| match i {
+                700_000 => Some(true),
+                999_999 => None,
+                _ => Some(false),
+            })
+            .collect::<Vec<_>>();
+        let ca = BooleanChunked::new("a", &values);
+        let out = ca.unique().unwrap();
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(&out), &[Some(false), Some(true), None]);
+
+        // without nulls the scan stops once both values are seen
+        let ca: BooleanChunked = (0..n).map(|i| i % 100 != 63).collect();
+        assert_eq!(Vec::from(&ca.unique().unwrap()), &[Some(true), Some(false)]);
+
+        let ca = BooleanChunked::full("a", true, n);
+        assert_eq!(Vec::from(&ca.unique().unwrap()), &[Some(true)]);
+
+        // sliced and chunked arrays don't start at a word boundary
+        let mut ca = BooleanChunked::new("a", &values[3..800_003]);
+        ca.append(&BooleanChunked::new("a", &values[800_003..]));
+        let s = ca.clone().into_series();
+        let out = s.unique().unwrap();
+        assert!(out.len() <= 3);
+        assert_eq!(Vec::from(out.bool().unwrap()), naive(&ca));
+        let sliced = ca.slice(500_000, 199_999);
+        assert_eq!(Vec::from(&sliced.unique().unwrap()), naive(&sliced));
+    }
+
+    #[test]
+    fn n_unique_bool() {
+        let cases: [(&[Option<bool>], usize); 5] = [
+            (&[Some(true), Some(true)], 1),
+            (&[Some(true), Some(false), Some(true)], 2),
+            (&[Some(true), None, Some(false)], 3),
+            (&[None, None], 1),
+            (&[], 0),
+        ];
+        for (values, expected) in cases {
+            let ca = BooleanChunked::new("a", values);
+            assert_eq!(ca.n_unique().unwrap(), expected, "{:?}", values);
+            // the generic path counts the first occurrences
+            assert_eq!(ca.arg_unique().unwrap().len(), expected);
+            assert_eq!(ca.into_series().n_unique().unwrap(), expected);
+        }
+    }
 }
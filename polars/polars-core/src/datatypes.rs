@@ -989,9 +989,11 @@ impl Field {
     }
 }
 
-impl From<&ArrowDataType> for DataType {
-    fn from(dt: &ArrowDataType) -> Self {
-        match dt {
+impl DataType {
+    /// Convert an arrow datatype to the corresponding polars dtype. Returns an error
+    /// naming the arrow datatype if polars does not support it.
+    pub fn try_from_arrow(dt: &ArrowDataType) -> Result<DataType> {
+        let dtype = match dt {
             ArrowDataType::Null => DataType::Null,
             ArrowDataType::UInt8 => DataType::UInt8,
             ArrowDataType::UInt16 => DataType::UInt16,
@@ -1004,8 +1006,9 @@ impl From<&ArrowDataType> for DataType {
             ArrowDataType::Boolean => DataType::Boolean,
             ArrowDataType::Float32 => DataType::Float32,
             ArrowDataType::Float64 => DataType::Float64,
-            ArrowDataType::LargeList(f) => DataType::List(Box::new(f.data_type().into())),
-            ArrowDataType::List(f) => DataType::List(Box::new(f.data_type().into())),
+            ArrowDataType::LargeList(f) | ArrowDataType::List(f) => {
+                DataType::List(Box::new(DataType::try_from_arrow(f.data_type())?))
+            }
             ArrowDataType::Date32 => DataType::Date,
             ArrowDataType::Timestamp(tu, tz) => DataType::Datetime(tu.into(), tz.clone()),
             ArrowDataType::Duration(tu) => DataType::Duration(tu.into()),
@@ -1017,7 +1020,15 @@ impl From<&ArrowDataType> for DataType {
             ArrowDataType::Dictionary(_, _, _) => DataType::Categorical(None),
             #[cfg(feature = "dtype-struct")]
             ArrowDataType::Struct(fields) => {
-                let fields: Vec<Field> = fields.iter().map(|fld| fld.into()).collect();
+                let fields = fields
+                    .iter()
+                    .map(|fld| {
+                        Ok(Field::new(
+                            &fld.name,
+                            DataType::try_from_arrow(&fld.data_type)?,
+                        ))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
                 DataType::Struct(fields)
             }
             ArrowDataType::Extension(name, _, _) if name == "POLARS_EXTENSION_TYPE" => {
@@ -1027,11 +1038,25 @@ impl From<&ArrowDataType> for DataType {
                 }
                 #[cfg(not(feature = "object"))]
                 {
-                    panic!("activate the 'object' feature to be able to load POLARS_EXTENSION_TYPE")
+                    return Err(PolarsError::ComputeError(
+                        "activate the 'object' feature to be able to load POLARS_EXTENSION_TYPE"
+                            .into(),
+                    ));
                 }
             }
-            dt => panic!("Arrow datatype {:?} not supported by Polars", dt),
-        }
+            dt => {
+                return Err(PolarsError::ComputeError(
+                    format!("Arrow datatype {:?} not supported by Polars", dt).into(),
+                ))
+            }
+        };
+        Ok(dtype)
+    }
+}
+
+impl From<&ArrowDataType> for DataType {
+    fn from(dt: &ArrowDataType) -> Self {
+        DataType::try_from_arrow(dt).unwrap_or_else(|e| panic!("{}", e))
     }
 }
 
@@ -1177,4 +1202,17 @@ mod test {
             assert_eq!(dt_p, dt);
         }
     }
+
+    #[test]
+    fn test_unsupported_arrow_dtype() {
+        let dt = ArrowDataType::List(Box::new(ArrowField::new(
+            "item",
+            ArrowDataType::Decimal(10, 2),
+            true,
+        )));
+        match DataType::try_from_arrow(&dt) {
+            Err(PolarsError::ComputeError(msg)) => assert!(msg.contains("Decimal(10, 2)")),
+            _ => panic!("expected a ComputeError"),
+        }
+    }
 }
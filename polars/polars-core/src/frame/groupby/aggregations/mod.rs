@@ -195,6 +195,63 @@ impl BooleanChunked {
     pub(crate) unsafe fn agg_sum(&self, groups: &GroupsProxy) -> Series {
         self.cast(&IDX_DTYPE).unwrap().agg_sum(groups)
     }
+
+    /// Whether any value in the group is `true`. The result is null if the group has no
+    /// `true` value but has a null value, and `false` for an empty group.
+    pub(crate) unsafe fn agg_any(&self, groups: &GroupsProxy) -> Series {
+        self.agg_kleene(groups, true)
+    }
+
+    /// Whether all values in the group are `true`. The result is null if the group has no
+    /// `false` value but has a null value, and `true` for an empty group.
+    pub(crate) unsafe fn agg_all(&self, groups: &GroupsProxy) -> Series {
+        self.agg_kleene(groups, false)
+    }
+
+    unsafe fn agg_kleene(&self, groups: &GroupsProxy, any: bool) -> Series {
+        let ca = self.rechunk();
+        let arr = ca.downcast_iter().next().unwrap();
+        let out: Vec<_> = match groups {
+            GroupsProxy::Idx(groups) => POOL.install(|| {
+                groups
+                    .all()
+                    .par_iter()
+                    .map(|idx| kleene_any_all(arr, idx.iter().map(|i| *i as usize), any))
+                    .collect()
+            }),
+            GroupsProxy::Slice { groups, .. } => POOL.install(|| {
+                groups
+                    .par_iter()
+                    .map(|&[first, len]| {
+                        kleene_any_all(arr, first as usize..(first + len) as usize, any)
+                    })
+                    .collect()
+            }),
+        };
+        let mut out: BooleanChunked = out.into_iter().collect();
+        out.rename(self.name());
+        out.into_series()
+    }
+}
+
+/// Compute `any` or `all` of the values at `idx` with Kleene logic, the same as
+/// [`BooleanChunked::any_kleene`] and [`BooleanChunked::all_kleene`]. The scan stops at the
+/// first `true` for `any` and at the first `false` for `all`. Otherwise the result is null if
+/// a value is null.
+fn kleene_any_all(arr: &BooleanArray, idx: impl Iterator<Item = usize>, any: bool) -> Option<bool> {
+    let mut has_nulls = false;
+    for i in idx {
+        if !arr.is_valid(i) {
+            has_nulls = true;
+        } else if arr.value(i) == any {
+            return Some(any);
+        }
+    }
+    if has_nulls {
+        None
+    } else {
+        Some(!any)
+    }
 }
 
 // implemented on the series because we don't need types
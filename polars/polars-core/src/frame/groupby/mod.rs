@@ -1295,4 +1295,101 @@ mod test {
         let _ = df.groupby(["g"])?.sum()?;
         Ok(())
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_groupby_agg_any_all() -> Result<()> {
+        let df = df![
+            "g" => ["a", "a", "a", "b", "b", "c", "c", "c"],
+            "flag" => [Some(true), Some(false), None, Some(false), Some(false), None, Some(true), None]
+        ]?;
+        let gb = df.groupby_stable(["g"])?;
+        let groups = gb.get_groups();
+        let flag = df.column("flag")?;
+
+        let any = unsafe { flag.agg_any(groups) };
+        assert_eq!(any.name(), "flag");
+        assert_eq!(
+            Vec::from(any.bool()?),
+            &[Some(true), Some(false), Some(true)]
+        );
+        let all = unsafe { flag.agg_all(groups) };
+        assert_eq!(Vec::from(all.bool()?), &[Some(false), Some(false), None]);
+
+        // a group with only nulls is null, a null decides if no value short circuits
+        let df = df![
+            "g" => [1, 1, 2, 2],
+            "flag" => [None, None, Some(false), None]
+        ]?;
+        let gb = df.groupby_stable(["g"])?;
+        let flag = df.column("flag")?;
+        let any = unsafe { flag.agg_any(gb.get_groups()) };
+        assert_eq!(Vec::from(any.bool()?), &[None, None]);
+        let all = unsafe { flag.agg_all(gb.get_groups()) };
+        assert_eq!(Vec::from(all.bool()?), &[None, Some(false)]);
+
+        // slice groups give the same result, an empty group is the same as on an empty array
+        let groups = GroupsProxy::Slice {
+            groups: vec![[0, 2], [2, 2], [4, 0]],
+            rolling: false,
+        };
+        let any = unsafe { flag.agg_any(&groups) };
+        assert_eq!(Vec::from(any.bool()?), &[None, None, Some(false)]);
+        let all = unsafe { flag.agg_all(&groups) };
+        assert_eq!(Vec::from(all.bool()?), &[None, Some(false), Some(true)]);
+        let empty = flag.bool()?.slice(0, 0);
+        assert_eq!(empty.any_kleene(), Some(false));
+        assert_eq!(empty.all_kleene(), Some(true));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_scatter_from_groups_bool() -> Result<()> {
+        let df = df![
+            "g" => ["a", "b", "a", "b", "a"],
+            "flag" => [Some(false), Some(false), Some(true), None, None]
+        ]?;
+        let gb = df.groupby_stable(["g"])?;
+        let groups = gb.get_groups();
+        let flag = df.column("flag")?;
+
+        // broadcast the result of `any` back to all rows of the group
+        let any = unsafe { flag.agg_any(groups) };
+        let out = flag.scatter_from_groups(groups, &any)?;
+        assert_eq!(
+            Vec::from(out.bool()?),
+            &[Some(true), None, Some(true), None, Some(true)]
+        );
+
+        // the same as a generic scatter with the values repeated per group
+        let mut idx = Vec::new();
+        let mut values = Vec::new();
+        for (group, value) in groups
+            .unwrap_idx()
+            .all()
+            .iter()
+            .zip(any.bool()?.into_iter())
+        {
+            idx.extend_from_slice(group);
+            values.extend(std::iter::repeat(value).take(group.len()));
+        }
+        let idx = IdxCa::from_vec("idx", idx);
+        let expected = flag.scatter(&idx, &Series::new("values", values))?;
+        assert!(out.series_equal_missing(&expected));
+
+        let groups = GroupsProxy::Slice {
+            groups: vec![[0, 2], [3, 2]],
+            rolling: false,
+        };
+        let out = flag.scatter_from_groups(&groups, &Series::new("values", &[true, false]))?;
+        assert_eq!(
+            Vec::from(out.bool()?),
+            &[Some(true), Some(true), Some(true), Some(false), Some(false)]
+        );
+        assert!(flag
+            .scatter_from_groups(&groups, &Series::new("values", &[true]))
+            .is_err());
+        Ok(())
+    }
 }
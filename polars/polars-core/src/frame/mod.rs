@@ -2640,6 +2640,9 @@ impl DataFrame {
     #[cfg_attr(docsrs, doc(cfg(feature = "zip_with")))]
     pub fn hmin(&self) -> Result<Option<Series>> {
         let min_fn = |acc: &Series, s: &Series| {
+            if acc.dtype() == &DataType::Boolean && s.dtype() == &DataType::Boolean {
+                return acc.min_elementwise(s);
+            }
             let mask = acc.lt(s)? & acc.is_not_null() | s.is_null();
             acc.zip_with(&mask, s)
         };
@@ -2670,6 +2673,9 @@ impl DataFrame {
     #[cfg_attr(docsrs, doc(cfg(feature = "zip_with")))]
     pub fn hmax(&self) -> Result<Option<Series>> {
         let max_fn = |acc: &Series, s: &Series| {
+            if acc.dtype() == &DataType::Boolean && s.dtype() == &DataType::Boolean {
+                return acc.max_elementwise(s);
+            }
             let mask = acc.gt(s)? & acc.is_not_null() | s.is_null();
             acc.zip_with(&mask, s)
         };
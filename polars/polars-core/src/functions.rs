@@ -5,6 +5,7 @@
 #[cfg(feature = "sort_multiple")]
 use crate::chunked_array::ops::sort::prepare_argsort;
 use crate::prelude::*;
+use crate::utils::bitmap::{bitmap_from_words, bitmap_words};
 #[cfg(feature = "diagonal_concat")]
 use crate::utils::concat_df;
 #[cfg(feature = "diagonal_concat")]
@@ -103,6 +104,102 @@ pub fn argsort_by(by: &[Series], reverse: &[bool]) -> Result<IdxCa> {
     first.argsort_multiple(&by, &reverse)
 }
 
+/// Add one to the bit-sliced counters of the positions of the set bits in `word`. Bit `i` of
+/// the counter of a position is stored in `planes[i]`.
+fn add_to_counters(planes: &mut [u64], mut carry: u64) {
+    for plane in planes {
+        let sum = *plane ^ carry;
+        carry &= *plane;
+        *plane = sum;
+        if carry == 0 {
+            break;
+        }
+    }
+}
+
+/// Get `true` at the positions where more than half of the boolean `series` are `true`.
+///
+/// Nulls are not counted, so the majority is taken over the non-null values at a position:
+/// `[true, false, null]` has no majority and `[true, null, null]` has. A position where all
+/// values are null is null. All series must be boolean and of equal length.
+///
+/// The votes of 64 positions are counted at once with bit-sliced counters, so no value is
+/// visited individually.
+pub fn boolean_majority(series: &[&Series]) -> Result<BooleanChunked> {
+    let first = series.first().ok_or_else(|| {
+        PolarsError::NoData("expected at least one series in boolean_majority".into())
+    })?;
+    let len = first.len();
+    let cas = series
+        .iter()
+        .map(|s| {
+            if s.len() != len {
+                return Err(PolarsError::ShapeMisMatch(
+                    format!(
+                        "all series in boolean_majority should have length {}, got {}",
+                        len,
+                        s.len()
+                    )
+                    .into(),
+                ));
+            }
+            Ok(s.bool()?.rechunk())
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // the words of the `true` and of the non-null values, `None` if there are no nulls
+    let mut true_words = Vec::with_capacity(cas.len());
+    let mut valid_words = Vec::with_capacity(cas.len());
+    for ca in &cas {
+        let arr = ca.downcast_iter().next().unwrap();
+        match arr.validity() {
+            Some(validity) => {
+                true_words.push(bitmap_words(&(arr.values() & validity)).collect::<Vec<_>>());
+                valid_words.push(Some(bitmap_words(validity).collect::<Vec<_>>()));
+            }
+            None => {
+                true_words.push(bitmap_words(arr.values()).collect());
+                valid_words.push(None);
+            }
+        }
+    }
+
+    let n_words = (len + 63) / 64;
+    // enough bits to count up to the number of series
+    let n_planes = (usize::BITS - cas.len().leading_zeros()) as usize;
+    let mut true_count = vec![0u64; n_planes];
+    let mut valid_count = vec![0u64; n_planes];
+    let mut values = Vec::with_capacity(n_words);
+    let mut validity = Vec::with_capacity(n_words);
+    for w in 0..n_words {
+        true_count.fill(0);
+        valid_count.fill(0);
+        for (trues, valids) in true_words.iter().zip(&valid_words) {
+            add_to_counters(&mut true_count, trues[w]);
+            add_to_counters(&mut valid_count, valids.as_ref().map_or(u64::MAX, |v| v[w]));
+        }
+        // compare `2 * true_count > valid_count`, starting at the most significant bit
+        let mut greater = 0;
+        let mut equal = u64::MAX;
+        for i in (0..=n_planes).rev() {
+            let double_true = if i == 0 { 0 } else { true_count[i - 1] };
+            let valid = valid_count.get(i).copied().unwrap_or(0);
+            greater |= equal & double_true & !valid;
+            equal &= !(double_true ^ valid);
+        }
+        values.push(greater);
+        validity.push(valid_count.iter().fold(0, |acc, plane| acc | plane));
+    }
+
+    let to_bitmap = |words: Vec<u64>| bitmap_from_words(&words, len);
+    let validity = valid_words
+        .iter()
+        .any(|v| v.is_some())
+        .then(|| to_bitmap(validity));
+    let arr = BooleanArray::from_data_default(to_bitmap(values), validity);
+    Ok((first.name(), arr).into())
+}
+
 // utility to be able to also add literals ot concat_str function
 #[cfg(feature = "concat_str")]
 enum IterBroadCast<'a> {
@@ -301,6 +398,59 @@ mod test {
         assert!((pearson_corr_f(a.f32().unwrap(), b.f32().unwrap()).unwrap() - 1.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_boolean_majority() -> Result<()> {
+        let a = Series::new(
+            "a",
+            &[Some(true), Some(true), Some(false), None, Some(true), None],
+        );
+        let b = Series::new(
+            "b",
+            &[
+                Some(true),
+                Some(false),
+                Some(false),
+                None,
+                Some(false),
+                None,
+            ],
+        );
+        let c = Series::new(
+            "c",
+            &[Some(false), Some(true), Some(false), Some(true), None, None],
+        );
+        let out = boolean_majority(&[&a, &b, &c])?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(&out),
+            &[
+                Some(true),
+                Some(true),
+                Some(false),
+                Some(true),
+                Some(false),
+                None
+            ]
+        );
+
+        // more than a single word
+        let series = [2, 3, 5]
+            .iter()
+            .map(|m| Series::new("", (0..150).map(|i| i % m == 0).collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+        let out = boolean_majority(&series.iter().collect::<Vec<_>>())?;
+        let expected = (0..150)
+            .map(|i| Some([2, 3, 5].iter().filter(|m| i % **m == 0).count() >= 2))
+            .collect::<Vec<_>>();
+        assert_eq!(Vec::from(&out), expected);
+        assert!(!out.has_validity());
+
+        assert!(boolean_majority(&[]).is_err());
+        assert!(boolean_majority(&[&a, &a.slice(0, 2)]).is_err());
+        assert!(boolean_majority(&[&a, &Series::new("d", &[1, 2, 3, 4, 5, 6])]).is_err());
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "concat_str")]
     fn test_concat_str() {
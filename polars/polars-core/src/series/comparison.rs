@@ -314,6 +314,59 @@ where
     }
 }
 
+/// Compare a boolean `Series` with `rhs` broadcast as a unit array, which the boolean
+/// kernels compare with bitmap operations.
+fn compare_bool_scalar(
+    s: &Series,
+    rhs: bool,
+    f: impl Fn(&BooleanChunked, &BooleanChunked) -> BooleanChunked,
+) -> Result<BooleanChunked> {
+    let ca = s.bool().map_err(|_| {
+        PolarsError::ComputeError(
+            format!(
+                "cannot compare bool value to series of type: {:?}",
+                s.dtype()
+            )
+            .into(),
+        )
+    })?;
+    let mut out = f(ca, &BooleanChunked::new("", &[rhs]));
+    out.rename(s.name());
+    Ok(out)
+}
+
+impl ChunkCompare<bool> for Series {
+    type Item = Result<BooleanChunked>;
+
+    fn eq_missing(&self, rhs: bool) -> Result<BooleanChunked> {
+        self.equal(rhs)
+    }
+
+    fn equal(&self, rhs: bool) -> Result<BooleanChunked> {
+        compare_bool_scalar(self, rhs, |lhs, rhs| lhs.equal(rhs))
+    }
+
+    fn not_equal(&self, rhs: bool) -> Result<BooleanChunked> {
+        compare_bool_scalar(self, rhs, |lhs, rhs| lhs.not_equal(rhs))
+    }
+
+    fn gt(&self, rhs: bool) -> Result<BooleanChunked> {
+        compare_bool_scalar(self, rhs, |lhs, rhs| lhs.gt(rhs))
+    }
+
+    fn gt_eq(&self, rhs: bool) -> Result<BooleanChunked> {
+        compare_bool_scalar(self, rhs, |lhs, rhs| lhs.gt_eq(rhs))
+    }
+
+    fn lt(&self, rhs: bool) -> Result<BooleanChunked> {
+        compare_bool_scalar(self, rhs, |lhs, rhs| lhs.lt(rhs))
+    }
+
+    fn lt_eq(&self, rhs: bool) -> Result<BooleanChunked> {
+        compare_bool_scalar(self, rhs, |lhs, rhs| lhs.lt_eq(rhs))
+    }
+}
+
 impl ChunkCompare<&str> for Series {
     type Item = Result<BooleanChunked>;
     fn eq_missing(&self, rhs: &str) -> Result<BooleanChunked> {
@@ -243,12 +243,31 @@ impl Series {
                 let keys = keys.as_any().downcast_ref::<PrimitiveArray<u32>>().unwrap();
                 let values = values.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
 
-                let mut builder = CategoricalChunkedBuilder::new(name, keys.len());
-                let iter = keys
-                    .into_iter()
-                    .map(|opt_key| opt_key.map(|k| values.value_unchecked(*k as usize)));
-                builder.drain_iter(iter);
-                Ok(builder.finish().into_series())
+                let n_values = values.len();
+                if let Some(key) = keys.iter().flatten().find(|k| **k as usize >= n_values) {
+                    return Err(PolarsError::ComputeError(
+                        format!(
+                            "dictionary key {} is out of bounds for {} dictionary values",
+                            key, n_values
+                        )
+                        .into(),
+                    ));
+                }
+
+                // a dictionary of unique values is the mapping, so the codes and the category
+                // order are kept, otherwise the categories are built in order of occurrence
+                match CategoricalChunked::from_keys_and_values(name, keys, values) {
+                    Some(ca) => Ok(ca.into_series()),
+                    None => {
+                        let mut builder = CategoricalChunkedBuilder::new(name, keys.len());
+                        let iter = keys.into_iter().map(|opt_key| {
+                            let k = *opt_key? as usize;
+                            values.is_valid(k).then(|| values.value(k))
+                        });
+                        builder.drain_iter(iter);
+                        Ok(builder.finish().into_series())
+                    }
+                }
             }
             #[cfg(not(feature = "dtype-u8"))]
             ArrowDataType::LargeBinary | ArrowDataType::Binary => {
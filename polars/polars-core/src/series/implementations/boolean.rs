@@ -18,7 +18,7 @@ use crate::series::implementations::SeriesWrap;
 use ahash::RandomState;
 use polars_arrow::prelude::QuantileInterpolOptions;
 use std::borrow::Cow;
-use std::ops::{BitAnd, BitOr, BitXor};
+use std::ops::{BitAnd, BitOr, BitXor, Not};
 
 impl IntoSeries for BooleanChunked {
     fn into_series(self) -> Series {
@@ -26,6 +26,20 @@ impl IntoSeries for BooleanChunked {
     }
 }
 
+fn check_broadcast_lengths(lhs: &BooleanChunked, rhs: &BooleanChunked) -> Result<()> {
+    if lhs.len() != rhs.len() && lhs.len() != 1 && rhs.len() != 1 {
+        return Err(PolarsError::ShapeMisMatch(
+            format!(
+                "cannot combine boolean Series of lengths {} and {}",
+                lhs.len(),
+                rhs.len()
+            )
+            .into(),
+        ));
+    }
+    Ok(())
+}
+
 impl private::PrivateSeries for SeriesWrap<BooleanChunked> {
     fn _field(&self) -> Cow<Field> {
         Cow::Borrowed(self.0.ref_field())
@@ -77,6 +91,14 @@ impl private::PrivateSeries for SeriesWrap<BooleanChunked> {
         self.0.agg_sum(groups)
     }
 
+    unsafe fn agg_any(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_any(groups)
+    }
+
+    unsafe fn agg_all(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_all(groups)
+    }
+
     unsafe fn agg_list(&self, groups: &GroupsProxy) -> Series {
         self.0.agg_list(groups)
     }
@@ -111,24 +133,35 @@ impl SeriesTrait for SeriesWrap<BooleanChunked> {
 
     #[cfg(feature = "interpolate")]
     fn interpolate(&self) -> Series {
-        self.0.clone().into_series()
+        self.0.interpolate().into_series()
     }
 
     fn bitxor(&self, other: &Series) -> Result<Series> {
-        let other = self.0.unpack_series_matching_type(other)?;
+        let other = other.bool()?;
         Ok((&self.0).bitxor(other).into_series())
     }
 
     fn bitand(&self, other: &Series) -> Result<Series> {
-        let other = self.0.unpack_series_matching_type(other)?;
+        let other = other.bool()?;
         Ok((&self.0).bitand(other).into_series())
     }
 
     fn bitor(&self, other: &Series) -> Result<Series> {
-        let other = self.0.unpack_series_matching_type(other)?;
+        let other = other.bool()?;
         Ok((&self.0).bitor(other).into_series())
     }
 
+    fn xor_mask(&self, other: &Series) -> Result<BooleanChunked> {
+        let other = other.bool()?;
+        check_broadcast_lengths(&self.0, other)?;
+        Ok((&self.0).bitxor(other))
+    }
+
+    fn implies(&self, other: &Series) -> Result<Series> {
+        let other = other.bool()?;
+        self.0.implies(other).map(|ca| ca.into_series())
+    }
+
     fn rename(&mut self, name: &str) {
         self.0.rename(name);
     }
@@ -273,7 +306,9 @@ impl SeriesTrait for SeriesWrap<BooleanChunked> {
     }
 
     fn has_validity(&self) -> bool {
-        self.0.has_validity()
+        // the null count of every chunk is stored in its validity bitmap, so this is O(1)
+        // per chunk and stops at the first chunk with a null value
+        self.0.downcast_iter().any(|arr| arr.null_count() > 0)
     }
 
     fn unique(&self) -> Result<Series> {
@@ -300,6 +335,93 @@ impl SeriesTrait for SeriesWrap<BooleanChunked> {
         Ok(self.0.arg_true())
     }
 
+    fn not(&self) -> Result<Series> {
+        Ok((&self.0).not().into_series())
+    }
+
+    fn count_true(&self) -> Result<usize> {
+        Ok(self.0.count_true())
+    }
+
+    fn count_false(&self) -> Result<usize> {
+        Ok(self.0.count_false())
+    }
+
+    fn ratio_true(&self) -> Result<Option<f64>> {
+        Ok(self.0.ratio_true())
+    }
+
+    #[cfg(feature = "cum_agg")]
+    fn cumany(&self, reverse: bool) -> Result<Series> {
+        Ok(self.0.cumany(reverse).into_series())
+    }
+
+    #[cfg(feature = "cum_agg")]
+    fn cumall(&self, reverse: bool) -> Result<Series> {
+        Ok(self.0.cumall(reverse).into_series())
+    }
+
+    fn hamming_distance(&self, other: &Series, nulls_differ: bool) -> Result<usize> {
+        let other = other.bool()?;
+        self.0.hamming_distance(other, nulls_differ)
+    }
+
+    fn weighted_true_sum(&self, weights: &Series) -> Result<f64> {
+        self.0.weighted_true_sum(weights)
+    }
+
+    fn head_non_null(&self, n: usize) -> Result<Series> {
+        Ok(self.0.head_non_null(n).into_series())
+    }
+
+    fn tail_non_null(&self, n: usize) -> Result<Series> {
+        Ok(self.0.tail_non_null(n).into_series())
+    }
+
+    fn scatter(&self, idx: &IdxCa, values: &Series) -> Result<Series> {
+        let values = values.bool()?;
+        Ok(self.0.scatter(idx, values)?.into_series())
+    }
+
+    fn take_every_offset(&self, n: usize, offset: usize) -> Result<Series> {
+        Ok(self.0.take_every_offset(n, offset)?.into_series())
+    }
+
+    fn scatter_from_groups(&self, groups: &GroupsProxy, values: &Series) -> Result<Series> {
+        let values = values.bool()?;
+        Ok(self.0.scatter_from_groups(groups, values)?.into_series())
+    }
+
+    fn rle(&self) -> Result<DataFrame> {
+        Ok(self.0.rle())
+    }
+
+    fn transitions(&self, include_first: bool) -> Result<IdxCa> {
+        Ok(self.0.transitions(include_first))
+    }
+
+    fn min_elementwise(&self, other: &Series) -> Result<Series> {
+        let other = other.bool()?;
+        Ok(self.0.min_elementwise(other)?.into_series())
+    }
+
+    fn max_elementwise(&self, other: &Series) -> Result<Series> {
+        let other = other.bool()?;
+        Ok(self.0.max_elementwise(other)?.into_series())
+    }
+
+    fn true_ranges(&self) -> Result<Vec<(usize, usize)>> {
+        Ok(self.0.true_ranges())
+    }
+
+    fn any(&self) -> Result<bool> {
+        Ok(self.0.any_ignore_nulls())
+    }
+
+    fn all(&self) -> Result<bool> {
+        Ok(self.0.all_ignore_nulls())
+    }
+
     fn is_null(&self) -> BooleanChunked {
         self.0.is_null()
     }
@@ -328,10 +450,20 @@ impl SeriesTrait for SeriesWrap<BooleanChunked> {
         ChunkShift::shift(&self.0, periods).into_series()
     }
 
+    fn shift_and_fill_bool(&self, periods: i64, fill: bool) -> Result<Series> {
+        Ok(ChunkShiftFill::shift_and_fill(&self.0, periods, Some(fill)).into_series())
+    }
+
     fn fill_null(&self, strategy: FillNullStrategy) -> Result<Series> {
         ChunkFillNull::fill_null(&self.0, strategy).map(|ca| ca.into_series())
     }
 
+    fn fill_null_with_value(&self, value: bool) -> Result<Series> {
+        self.0
+            .fill_null_with_values(value)
+            .map(|ca| ca.into_series())
+    }
+
     fn _sum_as_series(&self) -> Series {
         ChunkAggSeries::sum_as_series(&self.0)
     }
@@ -374,6 +506,11 @@ impl SeriesTrait for SeriesWrap<BooleanChunked> {
         RepeatBy::repeat_by(&self.0, by)
     }
 
+    #[cfg(feature = "repeat_by")]
+    fn repeat_by_scalar(&self, n: usize) -> Result<ListChunked> {
+        Ok(self.0.repeat_by_scalar(n))
+    }
+
     #[cfg(feature = "mode")]
     fn mode(&self) -> Result<Series> {
         Ok(self.0.mode()?.into_series())
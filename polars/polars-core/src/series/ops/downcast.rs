@@ -134,7 +134,8 @@ impl Series {
         }
     }
 
-    /// Unpack to ChunkedArray of dtype bool
+    /// Unpack to ChunkedArray of dtype bool. Returns a [`PolarsError::SchemaMisMatch`] that
+    /// names the dtype of the Series if it is not boolean.
     pub fn bool(&self) -> Result<&BooleanChunked> {
         match self.dtype() {
             DataType::Boolean => unsafe {
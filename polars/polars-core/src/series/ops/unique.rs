@@ -29,6 +29,11 @@ impl Series {
     /// Create a [`DataFrame`] with the unique `values` of this [`Series`] and a column `"counts"`
     /// with dtype [`IdxType`]
     pub fn value_counts(&self, multithreaded: bool) -> Result<DataFrame> {
+        // a boolean column has at most three distinct values, they are counted from the
+        // set bits of the chunks without hashing, so there is no work to parallelize
+        if let DataType::Boolean = self.dtype() {
+            return self.bool().unwrap().value_counts(true, true);
+        }
         let groups = self.group_tuples(multithreaded, false);
         let values = unsafe { self.agg_first(&groups) };
         let counts = groups.group_lengths("counts");
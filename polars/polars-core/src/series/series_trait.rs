@@ -128,6 +128,14 @@ pub(crate) mod private {
         unsafe fn agg_sum(&self, groups: &GroupsProxy) -> Series {
             Series::full_null(self._field().name(), groups.len(), self._dtype())
         }
+        /// Whether any value per group is `true`. Only supported for boolean dtype.
+        unsafe fn agg_any(&self, groups: &GroupsProxy) -> Series {
+            Series::full_null(self._field().name(), groups.len(), self._dtype())
+        }
+        /// Whether all values per group are `true`. Only supported for boolean dtype.
+        unsafe fn agg_all(&self, groups: &GroupsProxy) -> Series {
+            Series::full_null(self._field().name(), groups.len(), self._dtype())
+        }
         unsafe fn agg_std(&self, groups: &GroupsProxy) -> Series {
             Series::full_null(self._field().name(), groups.len(), self._dtype())
         }
@@ -201,6 +209,8 @@ pub trait SeriesTrait:
     /// Rename the Series.
     fn rename(&mut self, name: &str);
 
+    /// Bitwise AND. On boolean Series this is the logical AND with three-valued (Kleene)
+    /// logic: `false AND null == false`, otherwise null values propagate.
     fn bitand(&self, _other: &Series) -> Result<Series> {
         Err(PolarsError::InvalidOperation(
             format!(
@@ -211,6 +221,8 @@ pub trait SeriesTrait:
         ))
     }
 
+    /// Bitwise OR. On boolean Series this is the logical OR with three-valued (Kleene)
+    /// logic: `true OR null == true`, otherwise null values propagate.
     fn bitor(&self, _other: &Series) -> Result<Series> {
         Err(PolarsError::InvalidOperation(
             format!(
@@ -231,6 +243,31 @@ pub trait SeriesTrait:
         ))
     }
 
+    /// The positions where `self` and `other` differ as a mask that can be passed to
+    /// `filter`, i.e. the [`SeriesTrait::bitxor`] of two boolean Series without wrapping
+    /// it in a Series. Null values propagate. Only implemented for boolean dtype.
+    fn xor_mask(&self, _other: &Series) -> Result<BooleanChunked> {
+        Err(PolarsError::InvalidOperation(
+            format!(
+                "bitwise 'XOR' operation not supported for dtype {:?}",
+                self.dtype()
+            )
+            .into(),
+        ))
+    }
+
+    /// Logical implication `self → other` with three-valued (Kleene) logic, see
+    /// [`BooleanChunked::implies`]. Only implemented for boolean dtype.
+    fn implies(&self, _other: &Series) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            format!(
+                "logical implication not supported for dtype {:?}",
+                self.dtype()
+            )
+            .into(),
+        ))
+    }
+
     /// Get the lengths of the underlying chunks
     fn chunk_lengths(&self) -> ChunkIdIter {
         invalid_operation_panic!(self)
@@ -464,6 +501,164 @@ pub trait SeriesTrait:
         ))
     }
 
+    /// Negate the boolean values, null values stay null.
+    fn not(&self) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            "not can only be called for boolean dtype".into(),
+        ))
+    }
+
+    /// Count the `true` values, null values are ignored.
+    fn count_true(&self) -> Result<usize> {
+        Err(PolarsError::InvalidOperation(
+            "count_true can only be called for boolean dtype".into(),
+        ))
+    }
+
+    /// Count the `false` values, null values are ignored.
+    fn count_false(&self) -> Result<usize> {
+        Err(PolarsError::InvalidOperation(
+            "count_false can only be called for boolean dtype".into(),
+        ))
+    }
+
+    /// Get the fraction of the non-null values that are `true`.
+    /// See [`BooleanChunked::ratio_true`].
+    fn ratio_true(&self) -> Result<Option<f64>> {
+        Err(PolarsError::InvalidOperation(
+            "ratio_true can only be called for boolean dtype".into(),
+        ))
+    }
+
+    /// Get an array with the cumulative `any` computed at every element.
+    /// See [`BooleanChunked::cumany`].
+    #[cfg(feature = "cum_agg")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cum_agg")))]
+    fn cumany(&self, _reverse: bool) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            "cumany can only be called for boolean dtype".into(),
+        ))
+    }
+
+    /// Get an array with the cumulative `all` computed at every element.
+    /// See [`BooleanChunked::cumall`].
+    #[cfg(feature = "cum_agg")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cum_agg")))]
+    fn cumall(&self, _reverse: bool) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            "cumall can only be called for boolean dtype".into(),
+        ))
+    }
+
+    /// Count the positions where the values of `self` and `other` differ.
+    /// See [`BooleanChunked::hamming_distance`].
+    fn hamming_distance(&self, _other: &Series, _nulls_differ: bool) -> Result<usize> {
+        Err(PolarsError::InvalidOperation(
+            "hamming_distance can only be called for boolean dtype".into(),
+        ))
+    }
+
+    /// Sum the `weights` at the positions where the values are `true`.
+    /// See [`BooleanChunked::weighted_true_sum`].
+    fn weighted_true_sum(&self, _weights: &Series) -> Result<f64> {
+        Err(PolarsError::InvalidOperation(
+            "weighted_true_sum can only be called for boolean dtype".into(),
+        ))
+    }
+
+    /// Get the first `n` non-null values. See [`BooleanChunked::head_non_null`].
+    fn head_non_null(&self, _n: usize) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            "head_non_null can only be called for boolean dtype".into(),
+        ))
+    }
+
+    /// Get the last `n` non-null values. See [`BooleanChunked::tail_non_null`].
+    fn tail_non_null(&self, _n: usize) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            "tail_non_null can only be called for boolean dtype".into(),
+        ))
+    }
+
+    /// Overwrite the values at the indices in `idx` with `values`.
+    /// See [`BooleanChunked::scatter`].
+    fn scatter(&self, _idx: &IdxCa, _values: &Series) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            "scatter can only be called for boolean dtype".into(),
+        ))
+    }
+
+    /// Take every `n`th value, starting at index `offset`.
+    /// See [`BooleanChunked::take_every_offset`].
+    fn take_every_offset(&self, _n: usize, _offset: usize) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            "take_every_offset can only be called for boolean dtype".into(),
+        ))
+    }
+
+    /// Set the rows of every group to the value of that group in `values`.
+    /// See [`BooleanChunked::scatter_from_groups`].
+    fn scatter_from_groups(&self, _groups: &GroupsProxy, _values: &Series) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            "scatter_from_groups can only be called for boolean dtype".into(),
+        ))
+    }
+
+    /// Run-length encode the values. See [`BooleanChunked::rle`].
+    fn rle(&self) -> Result<DataFrame> {
+        Err(PolarsError::InvalidOperation(
+            "rle can only be called for boolean dtype".into(),
+        ))
+    }
+
+    /// Get the indexes where the value differs from the previous row.
+    /// See [`BooleanChunked::transitions`].
+    fn transitions(&self, _include_first: bool) -> Result<IdxCa> {
+        Err(PolarsError::InvalidOperation(
+            "transitions can only be called for boolean dtype".into(),
+        ))
+    }
+
+    /// Get the elementwise minimum with `other`, ignoring null values.
+    /// See [`BooleanChunked::min_elementwise`].
+    fn min_elementwise(&self, _other: &Series) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            "min_elementwise can only be called for boolean dtype".into(),
+        ))
+    }
+
+    /// Get the elementwise maximum with `other`, ignoring null values.
+    /// See [`BooleanChunked::max_elementwise`].
+    fn max_elementwise(&self, _other: &Series) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            "max_elementwise can only be called for boolean dtype".into(),
+        ))
+    }
+
+    /// Get the `(start, end)` ranges of every run of consecutive `true` values.
+    /// See [`BooleanChunked::true_ranges`].
+    fn true_ranges(&self) -> Result<Vec<(usize, usize)>> {
+        Err(PolarsError::InvalidOperation(
+            "true_ranges can only be called for boolean dtype".into(),
+        ))
+    }
+
+    /// Check if any value is `true`, null values are ignored.
+    /// See [`BooleanChunked::any_ignore_nulls`].
+    fn any(&self) -> Result<bool> {
+        Err(PolarsError::InvalidOperation(
+            "any can only be called for boolean dtype".into(),
+        ))
+    }
+
+    /// Check if all values are `true`, null values are ignored.
+    /// See [`BooleanChunked::all_ignore_nulls`].
+    fn all(&self) -> Result<bool> {
+        Err(PolarsError::InvalidOperation(
+            "all can only be called for boolean dtype".into(),
+        ))
+    }
+
     /// Get a mask of the null values.
     fn is_null(&self) -> BooleanChunked {
         invalid_operation_panic!(self)
@@ -527,6 +722,29 @@ pub trait SeriesTrait:
         invalid_operation_panic!(self)
     }
 
+    /// Shift the values by a given period and fill the parts that will be empty due to this operation
+    /// with `fill`. Only implemented for boolean dtype.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example() -> Result<()> {
+    ///     let s = Series::new("flags", &[true, true, true]);
+    ///
+    ///     let shifted = s.shift_and_fill_bool(1, false)?;
+    ///     assert_eq!(Vec::from(shifted.bool()?), &[Some(false), Some(true), Some(true)]);
+    ///
+    ///     Ok(())
+    /// }
+    /// example();
+    /// ```
+    fn shift_and_fill_bool(&self, _periods: i64, _fill: bool) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            "shift_and_fill_bool can only be called for boolean dtype".into(),
+        ))
+    }
+
     /// Replace None values with one of the following strategies:
     /// * Forward fill (replace None with the previous value)
     /// * Backward fill (replace None with the next value)
@@ -567,6 +785,14 @@ pub trait SeriesTrait:
         invalid_operation_panic!(self)
     }
 
+    /// Replace the null values with `value`, the result has no null values.
+    /// See [`ChunkFillNullValue::fill_null_with_values`].
+    fn fill_null_with_value(&self, _value: bool) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            "fill_null_with_value can only be called for boolean dtype".into(),
+        ))
+    }
+
     /// Get the sum of the Series as a new Series of length 1.
     ///
     /// If the [`DataType`] is one of `{Int8, UInt8, Int16, UInt16}` the `Series` is
@@ -652,6 +878,15 @@ pub trait SeriesTrait:
     fn repeat_by(&self, _by: &IdxCa) -> ListChunked {
         invalid_operation_panic!(self)
     }
+
+    /// Repeat every value `n` times. See [`BooleanChunked::repeat_by_scalar`].
+    #[cfg(feature = "repeat_by")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "repeat_by")))]
+    fn repeat_by_scalar(&self, _n: usize) -> Result<ListChunked> {
+        Err(PolarsError::InvalidOperation(
+            "repeat_by_scalar can only be called for boolean dtype".into(),
+        ))
+    }
     #[cfg(feature = "checked_arithmetic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "checked_arithmetic")))]
     fn checked_div(&self, _rhs: &Series) -> Result<Series> {
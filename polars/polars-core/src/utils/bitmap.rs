@@ -0,0 +1,40 @@
+//! Word level access to bitmaps for the kernels that process 64 bits at a time.
+use arrow::bitmap::Bitmap;
+
+/// Get the words of `bitmap`, least significant bit first. The bits after the end of the
+/// bitmap in the last word are unset.
+pub(crate) fn bitmap_words(bitmap: &Bitmap) -> impl Iterator<Item = u64> + '_ {
+    let chunks = bitmap.chunks::<u64>();
+    let remainder_len = chunks.remainder_len();
+    let remainder =
+        (remainder_len > 0).then(|| chunks.remainder() & (u64::MAX >> (64 - remainder_len)));
+    chunks.chain(remainder)
+}
+
+/// Create a bitmap of `len` bits from `words`, least significant bit first.
+pub(crate) fn bitmap_from_words(words: &[u64], len: usize) -> Bitmap {
+    let bytes = words.iter().flat_map(|w| w.to_le_bytes()).collect();
+    Bitmap::from_u8_vec(bytes, len)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bitmap_words_round_trip() {
+        let bits = (0..130).map(|i| i % 3 == 0).collect::<Vec<_>>();
+        let bitmap = Bitmap::from_iter(bits.iter().copied());
+        // a bitmap with an offset that is not a multiple of 8
+        let sliced = bitmap.clone().slice(5, 100);
+
+        for (bitmap, bits) in [(&bitmap, &bits[..]), (&sliced, &bits[5..105])] {
+            let words = bitmap_words(bitmap).collect::<Vec<_>>();
+            assert_eq!(words.len(), (bits.len() + 63) / 64);
+            // the bits after the end are unset
+            assert_eq!(words.last().unwrap() >> (bits.len() % 64), 0);
+            let out = bitmap_from_words(&words, bits.len());
+            assert_eq!(out.iter().collect::<Vec<_>>(), bits);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+pub(crate) mod bitmap;
 pub(crate) mod series;
 
 use crate::prelude::*;
@@ -130,6 +130,7 @@ where
             None,
             &arrow_schema,
             None,
+            None,
         )
     }
 }
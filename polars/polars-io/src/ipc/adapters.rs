@@ -0,0 +1,293 @@
+use super::read::coerce_large_type;
+#[cfg(feature = "lazy")]
+use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
+use crate::{ArrowReader, ArrowResult};
+use arrow::compute::cast;
+use arrow::io::ipc::read;
+use polars_core::frame::ArrowChunk;
+use polars_core::prelude::*;
+use std::io::{Read, Seek};
+#[cfg(feature = "lazy")]
+use std::sync::Arc;
+
+impl<R> ArrowReader for read::FileReader<R>
+where
+    R: Read + Seek,
+{
+    fn next_record_batch(&mut self) -> ArrowResult<Option<ArrowChunk>> {
+        self.next().map_or(Ok(None), |v| v.map(Some))
+    }
+}
+
+/// Statistics about the read of an IPC file, see
+/// [`IpcReader::finish_with_stats`](super::IpcReader::finish_with_stats).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadStats {
+    /// Number of rows in the `DataFrame`.
+    pub rows_read: usize,
+    /// Number of record batches the rows were read from.
+    pub batches_read: usize,
+    /// Whether all record batches of the file were read.
+    pub reached_eof: bool,
+}
+
+/// Counts the batches and rows that are read from the wrapped reader.
+pub(super) struct CountingReader<R> {
+    reader: R,
+    pub(super) batches: usize,
+    pub(super) rows: usize,
+    pub(super) eof: bool,
+}
+
+impl<R> CountingReader<R> {
+    pub(super) fn new(reader: R) -> Self {
+        Self {
+            reader,
+            batches: 0,
+            rows: 0,
+            eof: false,
+        }
+    }
+}
+
+impl<R: ArrowReader> ArrowReader for CountingReader<R> {
+    fn next_record_batch(&mut self) -> ArrowResult<Option<ArrowChunk>> {
+        let batch = self.reader.next_record_batch()?;
+        match &batch {
+            Some(batch) => {
+                self.batches += 1;
+                self.rows += batch.len();
+            }
+            None => self.eof = true,
+        }
+        Ok(batch)
+    }
+}
+
+/// Calls the progress callback after every record batch that is read from the wrapped reader.
+pub(super) struct ProgressReader<R> {
+    reader: R,
+    done: usize,
+    total: usize,
+    progress: Option<Box<dyn Fn(usize, usize) + Send>>,
+}
+
+impl<R> ProgressReader<R> {
+    pub(super) fn new(
+        reader: R,
+        total: usize,
+        progress: Option<Box<dyn Fn(usize, usize) + Send>>,
+    ) -> Self {
+        Self {
+            reader,
+            done: 0,
+            total,
+            progress,
+        }
+    }
+}
+
+impl<R: ArrowReader> ArrowReader for ProgressReader<R> {
+    fn next_record_batch(&mut self) -> ArrowResult<Option<ArrowChunk>> {
+        let batch = self.reader.next_record_batch()?;
+        if let (Some(_), Some(progress)) = (&batch, &self.progress) {
+            self.done += 1;
+            progress(self.done, self.total);
+        }
+        Ok(batch)
+    }
+}
+
+/// Casts the `Large*` arrays of the record batches of the wrapped reader to their standard
+/// counterparts, if `coerce` is set.
+pub(super) struct CoerceReader<R> {
+    reader: R,
+    coerce: bool,
+}
+
+impl<R> CoerceReader<R> {
+    pub(super) fn new(reader: R, coerce: bool) -> Self {
+        Self { reader, coerce }
+    }
+}
+
+impl<R: ArrowReader> ArrowReader for CoerceReader<R> {
+    fn next_record_batch(&mut self) -> ArrowResult<Option<ArrowChunk>> {
+        match self.reader.next_record_batch()? {
+            Some(batch) if self.coerce => {
+                let arrays = batch
+                    .into_arrays()
+                    .into_iter()
+                    .map(|arr| {
+                        let data_type = coerce_large_type(arr.data_type());
+                        if &data_type == arr.data_type() {
+                            Ok(arr)
+                        } else {
+                            // fails if the offsets don't fit in `i32`
+                            cast::cast(arr.as_ref(), &data_type, Default::default())
+                        }
+                    })
+                    .collect::<ArrowResult<Vec<_>>>()?;
+                Ok(Some(ArrowChunk::new(arrays)))
+            }
+            batch => Ok(batch),
+        }
+    }
+}
+
+/// Record batches that are already decoded.
+pub(super) struct DecodedBatches(pub(super) std::vec::IntoIter<ArrowChunk>);
+
+impl ArrowReader for DecodedBatches {
+    fn next_record_batch(&mut self) -> ArrowResult<Option<ArrowChunk>> {
+        Ok(self.0.next())
+    }
+}
+
+/// The sorted indices of the columns the `predicate` depends on, if the `predicate` can be
+/// evaluated on statistics.
+#[cfg(feature = "lazy")]
+pub(super) fn predicate_projection(
+    predicate: &dyn PhysicalIoExpr,
+    arrow_schema: &ArrowSchema,
+) -> Option<Vec<usize>> {
+    predicate.as_stats_evaluator()?;
+    let mut projection = predicate
+        .live_columns()?
+        .iter()
+        .map(|name| arrow_schema.fields.iter().position(|fld| &fld.name == name))
+        .collect::<Option<Vec<_>>>()?;
+    projection.sort_unstable();
+    projection.dedup();
+    Some(projection)
+}
+
+/// Reads the record batches of an IPC file and skips the batches of which the statistics
+/// show that they cannot match the predicate. The statistics are computed from the
+/// columns in `stats_projection`, which are decoded before the rest of the batch.
+#[cfg(feature = "lazy")]
+pub(super) struct StatisticsReader<'a, R> {
+    reader: &'a mut R,
+    metadata: read::FileMetadata,
+    dictionaries: read::Dictionaries,
+    projection: Option<Vec<usize>>,
+    stats_projection: Vec<usize>,
+    predicate: Arc<dyn PhysicalIoExpr>,
+    idx: usize,
+    scratch: Vec<u8>,
+}
+
+#[cfg(feature = "lazy")]
+impl<'a, R: Read + Seek> StatisticsReader<'a, R> {
+    pub(super) fn new(
+        reader: &'a mut R,
+        metadata: read::FileMetadata,
+        projection: Option<Vec<usize>>,
+        stats_projection: Vec<usize>,
+        predicate: Arc<dyn PhysicalIoExpr>,
+    ) -> Result<Self> {
+        let dictionaries = read::read_file_dictionaries(reader, &metadata)?;
+        Ok(Self {
+            reader,
+            metadata,
+            dictionaries,
+            projection,
+            stats_projection,
+            predicate,
+            idx: 0,
+            scratch: vec![],
+        })
+    }
+
+    fn should_read(&self, chunk: ArrowChunk) -> bool {
+        let mut schema = Schema::with_capacity(self.stats_projection.len());
+        let mut stats = Vec::with_capacity(self.stats_projection.len());
+        for (&i, arr) in self.stats_projection.iter().zip(chunk.into_arrays()) {
+            let name = &self.metadata.schema.fields[i].name;
+            match Series::try_from((name.as_str(), arr)) {
+                Ok(s) => {
+                    schema.with_column(name.clone(), s.dtype().clone());
+                    stats.push(ColumnStats::from_series(&s));
+                }
+                // we cannot compute statistics, read the batch
+                Err(_) => return true,
+            }
+        }
+        let stats = BatchStats::new(schema, stats);
+        let evaluator = self.predicate.as_stats_evaluator().unwrap();
+        !matches!(evaluator.should_read(&stats), Ok(false))
+    }
+}
+
+#[cfg(feature = "lazy")]
+impl<'a, R: Read + Seek> ArrowReader for StatisticsReader<'a, R> {
+    fn next_record_batch(&mut self) -> ArrowResult<Option<ArrowChunk>> {
+        while self.idx < self.metadata.blocks.len() {
+            let idx = self.idx;
+            self.idx += 1;
+
+            let stats_chunk = read::read_batch(
+                self.reader,
+                &self.dictionaries,
+                &self.metadata,
+                Some(self.stats_projection.as_slice()),
+                idx,
+                &mut self.scratch,
+            )?;
+            if !self.should_read(stats_chunk) {
+                continue;
+            }
+            return read::read_batch(
+                self.reader,
+                &self.dictionaries,
+                &self.metadata,
+                self.projection.as_deref(),
+                idx,
+                &mut self.scratch,
+            )
+            .map(Some);
+        }
+        Ok(None)
+    }
+}
+
+/// Skips the first `offset` rows of the record batches of the wrapped reader.
+pub(super) struct OffsetReader<R> {
+    reader: R,
+    remaining: usize,
+}
+
+impl<R> OffsetReader<R> {
+    pub(super) fn new(reader: R, offset: usize) -> Self {
+        Self {
+            reader,
+            remaining: offset,
+        }
+    }
+}
+
+impl<R: ArrowReader> ArrowReader for OffsetReader<R> {
+    fn next_record_batch(&mut self) -> ArrowResult<Option<ArrowChunk>> {
+        while self.remaining > 0 {
+            match self.reader.next_record_batch()? {
+                None => return Ok(None),
+                Some(batch) => {
+                    let len = batch.len();
+                    // the whole batch lies before the offset
+                    if len <= self.remaining {
+                        self.remaining -= len;
+                    } else {
+                        let offset = std::mem::take(&mut self.remaining);
+                        let arrays = batch
+                            .into_arrays()
+                            .into_iter()
+                            .map(|arr| arr.slice(offset, len - offset))
+                            .collect();
+                        return Ok(Some(ArrowChunk::new(arrays)));
+                    }
+                }
+            }
+        }
+        self.reader.next_record_batch()
+    }
+}
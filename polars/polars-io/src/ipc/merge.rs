@@ -0,0 +1,311 @@
+use super::read::to_polars_schema;
+use crate::prelude::*;
+use crate::utils::resolve_homedir;
+use crate::RowCount;
+use arrow::io::ipc::read;
+use polars_core::prelude::*;
+use std::cmp::Ordering;
+use std::path::PathBuf;
+
+impl IpcReader<std::fs::File> {
+    /// Read the IPC files at `paths`, e.g. the part files of a partitioned dataset, as a
+    /// single `DataFrame`. See [`IpcFilesReader`].
+    pub fn from_paths(paths: &[PathBuf]) -> IpcFilesReader {
+        IpcFilesReader {
+            paths: paths.to_vec(),
+            rechunk: true,
+            n_rows: None,
+            projection: None,
+            columns: None,
+            row_count: None,
+            sorted_merge_on: None,
+        }
+    }
+}
+
+/// Read multiple IPC files with the same schema and vertically concatenate them into a
+/// single `DataFrame`. Created with [`IpcReader::from_paths`].
+///
+/// The `n_rows` limit applies to the total number of rows over all files and a row count
+/// column continues counting over the file boundaries.
+#[must_use]
+pub struct IpcFilesReader {
+    paths: Vec<PathBuf>,
+    rechunk: bool,
+    n_rows: Option<usize>,
+    projection: Option<Vec<usize>>,
+    columns: Option<Vec<String>>,
+    row_count: Option<RowCount>,
+    sorted_merge_on: Option<String>,
+}
+
+impl IpcFilesReader {
+    /// Stop reading when `n` rows are read in total.
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Columns to select/ project. Selecting the same column twice results in an error.
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Add a `row_count` column that is continuous over all files. The `offset` of the
+    /// [`RowCount`] is the value of the first row of the first file, the first row of every
+    /// next file continues from the last row of the file before it.
+    pub fn with_row_count(mut self, row_count: Option<RowCount>) -> Self {
+        self.row_count = row_count;
+        self
+    }
+
+    /// Set the reader's column projection. This counts from 0, meaning that
+    /// `vec![0, 4]` would select the 1st and 5th column. Duplicate indices are not
+    /// deduplicated but result in an error.
+    pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Rechunk the concatenated `DataFrame` to contiguous memory.
+    pub fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    /// Merge the files on the column `key` instead of concatenating them, which results in a
+    /// `DataFrame` that is sorted on `key` if every file is sorted on it in ascending order.
+    /// Null values sort first and equal keys keep the order of the files. `Categorical` keys
+    /// are compared by their string values.
+    ///
+    /// Every file is read completely before merging, `n_rows` and the `row_count` are applied
+    /// to the merged result. A file that is not sorted on `key` results in an error. The `key`
+    /// column must be in the projection.
+    pub fn with_sorted_merge_on(mut self, key: Option<String>) -> Self {
+        self.sorted_merge_on = key;
+        self
+    }
+
+    pub fn finish(self) -> Result<DataFrame> {
+        if let Some(key) = &self.sorted_merge_on {
+            return self.finish_sorted_merge(key);
+        }
+        let mut paths = self.paths.iter();
+        let first = paths
+            .next()
+            .ok_or_else(|| PolarsError::NoData("no IPC files to read".into()))?;
+        let first = resolve_homedir(first);
+        let (reader, schema) = open_with_metadata(&first)?;
+
+        let read_file = |reader: IpcReader<std::fs::File>, n_rows_read: usize| {
+            let remaining = self.n_rows.map(|n| n - n_rows_read);
+            let row_count = self.row_count.clone().map(|mut rc| {
+                rc.offset += n_rows_read as IdxSize;
+                rc
+            });
+            reader
+                .with_n_rows(remaining)
+                .with_columns(self.columns.clone())
+                .with_projection(self.projection.clone())
+                .with_row_count(row_count)
+                .set_rechunk(false)
+                .finish()
+        };
+
+        let mut df = read_file(reader, 0)?;
+        for path in paths {
+            if matches!(self.n_rows, Some(n) if df.height() >= n) {
+                break;
+            }
+            let path = resolve_homedir(path);
+            let (reader, file_schema) = open_with_metadata(&path)?;
+            check_schema_equal(&schema, &file_schema, &first, &path)?;
+            let part = read_file(reader, df.height())?;
+            df.vstack_mut(&part)?;
+        }
+        if self.rechunk {
+            df.rechunk();
+        }
+        Ok(df)
+    }
+}
+
+impl IpcFilesReader {
+    fn finish_sorted_merge(&self, key: &str) -> Result<DataFrame> {
+        let mut frames = Vec::with_capacity(self.paths.len());
+        let mut first: Option<(PathBuf, ArrowSchema)> = None;
+        for path in &self.paths {
+            let path = resolve_homedir(path);
+            let (reader, file_schema) = open_with_metadata(&path)?;
+            if let Some((first_path, schema)) = &first {
+                check_schema_equal(schema, &file_schema, first_path, &path)?;
+            } else {
+                first = Some((path.clone(), file_schema));
+            }
+            let df = reader
+                .with_columns(self.columns.clone())
+                .with_projection(self.projection.clone())
+                .set_rechunk(false)
+                .finish()?;
+            frames.push((path, df));
+        }
+        if frames.is_empty() {
+            return Err(PolarsError::NoData("no IPC files to read".into()));
+        }
+
+        let mut df = merge_sorted(frames, key)?;
+        if let Some(n) = self.n_rows {
+            df = df.slice(0, n);
+        }
+        if let Some(rc) = &self.row_count {
+            df = df.with_row_count(&rc.name, Some(rc.offset))?;
+        }
+        Ok(df)
+    }
+}
+
+/// The column `key` of `df` as it is compared in a sorted merge. Categoricals are compared
+/// by their string values, the codes of different files don't follow the same order.
+fn merge_key(df: &DataFrame, key: &str) -> Result<Series> {
+    let s = df.column(key)?;
+    let out = match s.dtype() {
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_) => s.cast(&DataType::Utf8)?,
+        _ => s.to_physical_repr().into_owned(),
+    };
+    if !(out.dtype().is_numeric() || matches!(out.dtype(), DataType::Utf8)) {
+        return Err(PolarsError::ComputeError(
+            format!("cannot merge on column {:?} of dtype {:?}", key, s.dtype()).into(),
+        ));
+    }
+    Ok(out)
+}
+
+/// Whether `s` is sorted in ascending order with the null values first.
+fn is_sorted_nulls_first(s: &Series) -> Result<bool> {
+    if s.len() < 2 {
+        return Ok(true);
+    }
+    let prev = s.slice(0, s.len() - 1);
+    let next = s.slice(1, s.len() - 1);
+    let smaller = next.lt(&prev)?;
+    let null_after_value = &next.is_null() & &prev.is_not_null();
+    Ok(!(smaller.into_iter().any(|v| v == Some(true)) || null_after_value.any()))
+}
+
+/// Merge `frames`, which are each sorted on the column `key`, into a single sorted
+/// `DataFrame`. On equal keys the rows of the earlier frame come first.
+fn merge_sorted(frames: Vec<(PathBuf, DataFrame)>, key: &str) -> Result<DataFrame> {
+    let mut keys: Option<Series> = None;
+    for (path, df) in &frames {
+        let s = merge_key(df, key)?;
+        if !is_sorted_nulls_first(&s)? {
+            return Err(PolarsError::ComputeError(
+                format!("file {:?} is not sorted on column {:?}", path, key).into(),
+            ));
+        }
+        match &mut keys {
+            Some(keys) => {
+                keys.append(&s)?;
+            }
+            None => keys = Some(s),
+        }
+    }
+
+    let mut frames = frames.into_iter().map(|(_, df)| df);
+    let mut df = frames.next().unwrap();
+    for other in frames {
+        df.vstack_mut(&other)?;
+    }
+    // the argsort is stable, so equal keys keep the order of the files and the order
+    // within a file
+    let idx = keys.unwrap().argsort(SortOptions {
+        descending: false,
+        nulls_last: false,
+    });
+    df.take(&idx)
+}
+
+/// Open the IPC file at `path` and read its footer once. The metadata is passed on to the
+/// returned reader, so it is not read again.
+fn open_with_metadata(path: &std::path::Path) -> Result<(IpcReader<std::fs::File>, ArrowSchema)> {
+    let mut file = std::fs::File::open(path)?;
+    let metadata = read::read_file_metadata(&mut file)?;
+    // fail early on dtypes polars does not support
+    to_polars_schema(&metadata.schema)?;
+    let schema = metadata.schema.clone();
+    Ok((IpcReader::new(file).with_metadata(metadata), schema))
+}
+
+/// Describe the first field in which the schemas differ by name, position or dtype.
+fn schema_mismatch(a: &ArrowSchema, b: &ArrowSchema) -> Option<String> {
+    for (i, (fld_a, fld_b)) in a.fields.iter().zip(&b.fields).enumerate() {
+        if fld_a.name != fld_b.name {
+            return Some(
+                match b.fields.iter().position(|fld| fld.name == fld_a.name) {
+                    Some(j) => format!(
+                        "column {:?} is at position {} in the first schema and at position {} in the second",
+                        fld_a.name, i, j
+                    ),
+                    None => format!(
+                        "column {:?} at position {} in the first schema is missing in the second, which has column {:?} there",
+                        fld_a.name, i, fld_b.name
+                    ),
+                },
+            );
+        }
+        if fld_a.data_type != fld_b.data_type {
+            return Some(format!(
+                "column {:?} has dtype {:?} in the first schema and dtype {:?} in the second",
+                fld_a.name, fld_a.data_type, fld_b.data_type
+            ));
+        }
+    }
+    match a.fields.len().cmp(&b.fields.len()) {
+        Ordering::Greater => Some(format!(
+            "column {:?} of the first schema is missing in the second",
+            a.fields[b.fields.len()].name
+        )),
+        Ordering::Less => Some(format!(
+            "column {:?} of the second schema is missing in the first",
+            b.fields[a.fields.len()].name
+        )),
+        Ordering::Equal => None,
+    }
+}
+
+/// Check that the data of two IPC files with schemas `a` and `b` can be combined, e.g. by
+/// reading them into a single `DataFrame` or appending to a file. The schemas must have the
+/// same field names in the same order and with the same dtypes; nullability and custom
+/// metadata are not compared.
+///
+/// The error names the first field that differs, in which schema and how.
+pub fn ipc_schemas_compatible(a: &ArrowSchema, b: &ArrowSchema) -> Result<()> {
+    match schema_mismatch(a, b) {
+        None => Ok(()),
+        Some(msg) => Err(PolarsError::SchemaMisMatch(
+            format!("IPC schemas are not compatible; {}", msg).into(),
+        )),
+    }
+}
+
+/// Check that the schema of the file at `path` is compatible with the schema of the file at
+/// `expected_path`, see [`ipc_schemas_compatible`].
+fn check_schema_equal(
+    expected: &ArrowSchema,
+    schema: &ArrowSchema,
+    expected_path: &std::path::Path,
+    path: &std::path::Path,
+) -> Result<()> {
+    match schema_mismatch(expected, schema) {
+        None => Ok(()),
+        Some(msg) => Err(PolarsError::SchemaMisMatch(
+            format!(
+                "schema of IPC file {:?} does not match the schema of {:?}; {}",
+                path, expected_path, msg
+            )
+            .into(),
+        )),
+    }
+}
@@ -0,0 +1,2101 @@
+//! # (De)serializing Arrows IPC format.
+//!
+//! Arrow IPC is a [binary format format](https://arrow.apache.org/docs/python/ipc.html).
+//! It is the recommended way to serialize and deserialize Polars DataFrames as this is most true
+//! to the data schema.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use polars_core::prelude::*;
+//! use polars_io::prelude::*;
+//! use std::io::Cursor;
+//!
+//!
+//! let s0 = Series::new("days", &[0, 1, 2, 3, 4]);
+//! let s1 = Series::new("temp", &[22.1, 19.9, 7., 2., 3.]);
+//! let mut df = DataFrame::new(vec![s0, s1]).unwrap();
+//!
+//! // Create an in memory file handler.
+//! // Vec<u8>: Read + Write
+//! // Cursor<T>: Seek
+//!
+//! let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+//!
+//! // write to the in memory buffer
+//! IpcWriter::new(&mut buf).finish(&mut df).expect("ipc writer");
+//!
+//! // reset the buffers index after writing to the beginning of the buffer
+//! buf.set_position(0);
+//!
+//! // read the buffer into a DataFrame
+//! let df_read = IpcReader::new(buf).finish().unwrap();
+//! assert!(df.frame_equal(&df_read));
+//! ```
+mod adapters;
+mod merge;
+mod read;
+mod stream;
+mod write;
+
+pub use crate::predicates::{BatchStats, ColumnStats};
+pub use adapters::ReadStats;
+pub use merge::*;
+pub use read::*;
+pub use stream::*;
+pub use write::*;
+
+#[cfg(test)]
+mod test {
+    use super::read::CONTINUATION_MARKER;
+    use super::write::split_by_batch_size;
+    use crate::prelude::*;
+    use crate::RowCount;
+    use arrow::io::ipc::{read, write, IpcField};
+    use arrow_format::ipc::MetadataVersion;
+    use polars_core::df;
+    use polars_core::prelude::*;
+    use std::collections::BTreeMap;
+    use std::io::Cursor;
+
+    #[test]
+    fn write_and_read_ipc() {
+        // Vec<T> : Write + Read
+        // Cursor<Vec<_>>: Seek
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+
+        IpcWriter::new(&mut buf)
+            .finish(&mut df)
+            .expect("ipc writer");
+
+        buf.set_position(0);
+
+        let df_read = IpcReader::new(buf).finish().unwrap();
+        assert!(df.frame_equal(&df_read));
+    }
+
+    #[test]
+    fn write_ipc_multiple_chunks() -> Result<()> {
+        let mut a = Series::new("a", &[1i32, 2, 3]);
+        a.append(&Series::new("a", &[4i32, 5, 6, 7, 8, 9, 10]))?;
+        let mut b = Series::new("b", &[1.0f64, 2.0, 3.0, 4.0, 5.0]);
+        b.append(&Series::new("b", &[6.0f64, 7.0, 8.0, 9.0, 10.0]))?;
+        let mut df = DataFrame::new(vec![a, b])?;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+        // the misaligned chunks are split instead of merged
+        assert_eq!(df.column("a")?.n_chunks(), 2);
+        let metadata = IpcReader::new(buf.clone()).metadata()?;
+        assert_eq!(metadata.batch_lengths, &[3, 2, 5]);
+        let df_read = IpcReader::new(buf).finish()?;
+        assert!(df.frame_equal(&df_read));
+
+        let mut df = create_df();
+        df.vstack_mut(&create_df())?;
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+        let metadata = IpcReader::new(buf.clone()).metadata()?;
+        assert_eq!(metadata.batch_lengths, &[5, 5]);
+        let df_read = IpcReader::new(buf).finish()?;
+        assert!(df.frame_equal(&df_read));
+        Ok(())
+    }
+
+    #[test]
+    fn write_ipc_with_batch_size() -> Result<()> {
+        let mut df = df!(
+            "a" => (0..1000).collect::<Vec<i32>>(),
+            "b" => (0..1000).map(|v| v % 3 == 0).collect::<Vec<_>>()
+        )?;
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        IpcWriter::new(&mut buf)
+            .with_batch_size(Some(256))
+            .finish(&mut df)?;
+        buf.set_position(0);
+
+        let metadata = IpcReader::new(buf.clone()).metadata()?;
+        assert_eq!(metadata.num_batches, 4);
+        assert_eq!(metadata.batch_lengths, &[256, 256, 256, 232]);
+        let df_read = IpcReader::new(buf).finish()?;
+        assert!(df.frame_equal(&df_read));
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        assert!(IpcWriter::new(&mut buf)
+            .with_batch_size(Some(0))
+            .finish(&mut df)
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_without_rechunk() -> Result<()> {
+        let mut df = df!(
+            "a" => (0..30).collect::<Vec<i32>>(),
+            "b" => (0..30).map(|v| v.to_string()).collect::<Vec<_>>()
+        )?;
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        IpcWriter::new(&mut buf)
+            .with_batch_size(Some(10))
+            .finish(&mut df)?;
+        buf.set_position(0);
+
+        // a chunk per record batch
+        let df_read = IpcReader::new(buf.clone()).set_rechunk(false).finish()?;
+        assert_eq!(df_read.n_chunks()?, 3);
+        assert!(df_read.frame_equal(&df));
+
+        // the first record batch is skipped
+        let df_read = IpcReader::new(buf.clone())
+            .set_rechunk(false)
+            .with_row_offset(Some(15))
+            .finish()?;
+        assert_eq!(df_read.n_chunks()?, 2);
+        assert!(df_read.frame_equal(&df.slice(15, 15)));
+
+        // the sliced last batch is not merged with the others
+        let df_read = IpcReader::new(buf.clone())
+            .set_rechunk(false)
+            .with_n_rows(Some(25))
+            .finish()?;
+        assert_eq!(df_read.n_chunks()?, 3);
+        assert!(df_read.frame_equal(&df.head(Some(25))));
+
+        let df_read = IpcReader::new(buf).finish()?;
+        assert_eq!(df_read.n_chunks()?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn write_ipc_booleans_bit_packed() -> Result<()> {
+        let n = 10_000;
+        let columns = (0..4)
+            .map(|c| {
+                Series::new(
+                    &format!("flag_{}", c),
+                    (0..n).map(|i| (i + c) % 3 == 0).collect::<Vec<_>>(),
+                )
+            })
+            .chain(std::iter::once(Series::new(
+                "with_nulls",
+                (0..n)
+                    .map(|i| if i % 5 == 0 { None } else { Some(i % 2 == 0) })
+                    .collect::<Vec<_>>(),
+            )))
+            .collect::<Vec<_>>();
+        let mut df = DataFrame::new(columns)?;
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+
+        // 5 value buffers and a single validity buffer of a bit per row, padded to 64 bytes,
+        // a byte per value would take 50_000 bytes
+        let packed = 6 * ((n / 8 + 63) / 64 * 64);
+        let size = buf.get_ref().len();
+        assert!(
+            size < packed + 2048,
+            "{} bytes for {} packed bytes",
+            size,
+            packed
+        );
+
+        buf.set_position(0);
+        let metadata = read::read_file_metadata(&mut buf)?;
+        assert!(metadata
+            .schema
+            .fields
+            .iter()
+            .all(|fld| fld.data_type == ArrowDataType::Boolean));
+        let df_read = IpcReader::new(buf).finish()?;
+        assert!(df_read.frame_equal_missing(&df));
+        Ok(())
+    }
+
+    #[test]
+    fn write_ipc_preserve_chunks() -> Result<()> {
+        let mut df = create_df();
+        df.vstack_mut(&df!("days" => [5, 6], "temp" => [1.0, 2.0])?)?;
+        df.vstack_mut(&df!("days" => [7, 8, 9], "temp" => [3.0, 4.0, 5.0])?)?;
+        assert_eq!(df.n_chunks()?, 3);
+
+        let write = |df: &mut DataFrame, preserve_chunks: bool, batch_size: Option<usize>| {
+            let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+            IpcWriter::new(&mut buf)
+                .with_preserve_chunks(preserve_chunks)
+                .with_batch_size(batch_size)
+                .finish(df)?;
+            buf.set_position(0);
+            assert!(IpcReader::new(buf.clone()).finish()?.frame_equal(df));
+            IpcReader::new(buf).metadata().map(|md| md.batch_lengths)
+        };
+        assert_eq!(write(&mut df, true, None)?, &[5, 2, 3]);
+        assert_eq!(write(&mut df, false, None)?, &[10]);
+        assert_eq!(write(&mut df, true, Some(4))?, &[4, 1, 2, 3]);
+        assert_eq!(write(&mut df, false, Some(4))?, &[4, 4, 2]);
+        // the DataFrame that is written is not rechunked
+        assert_eq!(df.n_chunks()?, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn ipc_bytes_round_trip() -> Result<()> {
+        let mut df = create_df();
+        for compression in [None, Some(IpcCompression::LZ4), Some(IpcCompression::ZSTD)] {
+            let bytes = write_ipc_to_vec(&mut df, compression)?;
+            let df_read = read_ipc_from_slice(&bytes)?;
+            assert!(df.frame_equal(&df_read), "{:?}", compression);
+
+            let df_read = IpcReader::from_slice(&bytes)
+                .with_columns(Some(vec!["temp".to_string()]))
+                .finish()?;
+            assert!(df_read.frame_equal(&df.select(["temp"])?));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_ipc_with_projection() {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3], "b" => [2, 3, 4], "c" => [3, 4, 5]).unwrap();
+
+        IpcWriter::new(&mut buf)
+            .finish(&mut df)
+            .expect("ipc writer");
+        buf.set_position(0);
+
+        let expected = df!("b" => [2, 3, 4], "c" => [3, 4, 5]).unwrap();
+        let df_read = IpcReader::new(buf)
+            .with_projection(Some(vec![1, 2]))
+            .finish()
+            .unwrap();
+        assert_eq!(df_read.shape(), (3, 2));
+        df_read.frame_equal(&expected);
+    }
+
+    #[test]
+    fn test_read_ipc_with_columns() {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3], "b" => [2, 3, 4], "c" => [3, 4, 5]).unwrap();
+
+        IpcWriter::new(&mut buf)
+            .finish(&mut df)
+            .expect("ipc writer");
+        buf.set_position(0);
+
+        let expected = df!("b" => [2, 3, 4], "c" => [3, 4, 5]).unwrap();
+        let df_read = IpcReader::new(buf)
+            .with_columns(Some(vec!["c".to_string(), "b".to_string()]))
+            .finish()
+            .unwrap();
+        df_read.frame_equal(&expected);
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df![
+            "a" => ["x", "y", "z"],
+            "b" => [123, 456, 789],
+            "c" => [4.5, 10.0, 10.0],
+            "d" => ["misc", "other", "value"],
+        ]
+        .unwrap();
+        IpcWriter::new(&mut buf)
+            .finish(&mut df)
+            .expect("ipc writer");
+        buf.set_position(0);
+        let expected = df![
+            "a" => ["x", "y", "z"],
+            "c" => [4.5, 10.0, 10.0],
+            "d" => ["misc", "other", "value"],
+            "b" => [123, 456, 789],
+        ]
+        .unwrap();
+        let df_read = IpcReader::new(buf)
+            .with_columns(Some(vec![
+                "a".to_string(),
+                "c".to_string(),
+                "d".to_string(),
+                "b".to_string(),
+            ]))
+            .finish()
+            .unwrap();
+        df_read.frame_equal(&expected);
+    }
+
+    #[test]
+    fn test_write_with_compression() {
+        let mut df = create_df();
+
+        let compressions = vec![
+            None,
+            Some(write::Compression::LZ4),
+            Some(write::Compression::ZSTD),
+        ];
+
+        for compression in compressions.into_iter() {
+            let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+            IpcWriter::new(&mut buf)
+                .with_compression(compression)
+                .finish(&mut df)
+                .expect("ipc writer");
+            buf.set_position(0);
+
+            let df_read = IpcReader::new(buf)
+                .finish()
+                .expect(&format!("IPC reader: {:?}", compression));
+            assert!(df.frame_equal(&df_read));
+        }
+    }
+
+    #[test]
+    fn write_and_read_ipc_stream() {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+
+        IpcStreamWriter::new(&mut buf)
+            .finish(&mut df)
+            .expect("ipc stream writer");
+        buf.set_position(0);
+
+        let df_read = IpcStreamReader::new(buf).finish().unwrap();
+        assert!(df.frame_equal(&df_read));
+    }
+
+    #[test]
+    fn test_write_stream_with_compression() -> Result<()> {
+        let mut df = create_df();
+
+        let compressions = [
+            None,
+            Some(write::Compression::LZ4),
+            Some(write::Compression::ZSTD),
+        ];
+
+        for compression in compressions {
+            let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+            IpcStreamWriter::new(&mut buf)
+                .with_compression(compression)
+                .finish(&mut df)?;
+            buf.set_position(0);
+
+            let df_read = IpcStreamReader::new(buf).finish()?;
+            assert!(df.frame_equal(&df_read), "{:?}", compression);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn write_and_read_ipc_stream_multiple_chunks() {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+        df.vstack_mut(&create_df()).unwrap();
+        assert_eq!(df.n_chunks().unwrap(), 2);
+
+        IpcStreamWriter::new(&mut buf)
+            .finish(&mut df)
+            .expect("ipc stream writer");
+        buf.set_position(0);
+
+        let df_read = IpcStreamReader::new(buf)
+            .set_rechunk(false)
+            .finish()
+            .unwrap();
+        assert_eq!(df_read.n_chunks().unwrap(), 2);
+        assert!(df.frame_equal(&df_read));
+    }
+
+    #[test]
+    fn write_and_read_ipc_stream_misaligned_chunks() -> Result<()> {
+        let mut a = Series::new("a", &[1, 2, 3]);
+        a.append(&Series::new("a", &[4, 5, 6, 7, 8, 9, 10]))?;
+        let mut b = Series::new("b", &[1.0, 2.0, 3.0, 4.0, 5.0]);
+        b.append(&Series::new("b", &[6.0, 7.0, 8.0, 9.0, 10.0]))?;
+        let mut df = DataFrame::new(vec![a, b])?;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        IpcStreamWriter::new(&mut buf).finish(&mut df)?;
+        // the frame is not rechunked to write it
+        assert_eq!(df.column("a")?.chunk_lengths().collect::<Vec<_>>(), &[3, 7]);
+        assert_eq!(df.column("b")?.chunk_lengths().collect::<Vec<_>>(), &[5, 5]);
+        buf.set_position(0);
+
+        let df_read = IpcStreamReader::new(buf).set_rechunk(false).finish()?;
+        assert_eq!(
+            df_read.column("a")?.chunk_lengths().collect::<Vec<_>>(),
+            &[3, 2, 5]
+        );
+        assert!(df.frame_equal(&df_read));
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_stream_with_columns_and_n_rows() {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3], "b" => [2, 3, 4], "c" => [3, 4, 5]).unwrap();
+
+        IpcStreamWriter::new(&mut buf)
+            .finish(&mut df)
+            .expect("ipc stream writer");
+        buf.set_position(0);
+
+        let expected = df!("c" => [3, 4], "a" => [1, 2]).unwrap();
+        let df_read = IpcStreamReader::new(buf)
+            .with_columns(Some(vec!["c".to_string(), "a".to_string()]))
+            .with_n_rows(Some(2))
+            .finish()
+            .unwrap();
+        assert!(df_read.frame_equal(&expected));
+    }
+
+    #[test]
+    fn write_and_read_ipc_batched() {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let df = create_df();
+
+        let mut writer = IpcWriter::new(&mut buf).batched(&df.schema()).unwrap();
+        writer.write_batch(&df).unwrap();
+        writer.write_batch(&df).unwrap();
+        writer.finish().unwrap();
+        buf.set_position(0);
+
+        let mut expected = df.clone();
+        expected.vstack_mut(&df).unwrap();
+        let df_read = IpcReader::new(buf).finish().unwrap();
+        assert!(df_read.frame_equal(&expected));
+    }
+
+    #[test]
+    fn write_ipc_batched_finalize_partial() -> Result<()> {
+        let df = create_df();
+        let mut two = df.clone();
+        two.vstack_mut(&df)?;
+        let mut three = two.clone();
+        three.vstack_mut(&df)?;
+
+        // the producer stops after the partial footer
+        let mut buf: Vec<u8> = Vec::new();
+        let mut writer = IpcWriter::new(&mut buf).batched(&df.schema())?;
+        writer.write_batch(&df)?;
+        writer.flush()?;
+        writer.write_batch(&df)?;
+        let len = writer.finalize_partial()?;
+        drop(writer);
+        assert_eq!(len, buf.len() as u64);
+        assert!(read_ipc_from_slice(&buf)?.frame_equal(&two));
+
+        // the producer stops after writing another batch
+        let mut buf: Vec<u8> = Vec::new();
+        let mut writer = IpcWriter::new(&mut buf).batched(&df.schema())?;
+        writer.write_batch(&df)?;
+        writer.write_batch(&df)?;
+        let len = writer.finalize_partial()?;
+        writer.write_batch(&df)?;
+        drop(writer);
+        buf.truncate(len as usize);
+        assert!(read_ipc_from_slice(&buf)?.frame_equal(&two));
+
+        // the file is finished after a partial footer
+        let mut buf: Vec<u8> = Vec::new();
+        let mut writer = IpcWriter::new(&mut buf).batched(&df.schema())?;
+        writer.write_batch(&df)?;
+        writer.write_batch(&df)?;
+        writer.finalize_partial()?;
+        writer.write_batch(&df)?;
+        writer.finish()?;
+        drop(writer);
+        let df_read = IpcReader::from_slice(&buf).with_verify(true).finish()?;
+        assert!(df_read.frame_equal(&three));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn write_ipc_batched_dictionaries() -> Result<()> {
+        let mut df = df! {
+            "cat" => ["a", "b", "a", "c"],
+            "int" => [1, 2, 3, 4]
+        }?;
+        df.try_apply("cat", |s| s.cast(&DataType::Categorical(None)))?;
+        let mut two = df.clone();
+        two.vstack_mut(&df)?;
+
+        for compression in [None, Some(write::Compression::LZ4)] {
+            // the dictionary is written once, before the first batch
+            let mut buf: Vec<u8> = Vec::new();
+            let mut writer = IpcWriter::new(&mut buf)
+                .with_compression(compression)
+                .batched(&df.schema())?;
+            writer.write_batch(&df)?;
+            let len = writer.finalize_partial()?;
+            writer.write_batch(&df)?;
+            writer.finish()?;
+            drop(writer);
+
+            let df_read = IpcReader::from_slice(&buf).with_verify(true).finish()?;
+            assert!(df_read
+                .column("cat")?
+                .cast(&DataType::Utf8)?
+                .series_equal(&two.column("cat")?.cast(&DataType::Utf8)?));
+            assert!(df_read.column("int")?.series_equal(two.column("int")?));
+
+            buf.truncate(len as usize);
+            let df_read = read_ipc_from_slice(&buf)?;
+            assert_eq!(df_read.height(), df.height());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn write_ipc_batched_schema_mismatch() {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let df = create_df();
+
+        let mut writer = IpcWriter::new(&mut buf).batched(&df.schema()).unwrap();
+        writer.write_batch(&df).unwrap();
+        let other = df!("days" => [0, 1], "temp" => ["a", "b"]).unwrap();
+        assert!(matches!(
+            writer.write_batch(&other),
+            Err(PolarsError::SchemaMisMatch(_))
+        ));
+    }
+
+    #[test]
+    fn read_ipc_with_schema_overwrite() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1i32, 2, 3], "b" => ["x", "y", "z"])?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+
+        let overwrite = Schema::from(vec![Field::new("a", DataType::Int64)]);
+        let df_read = IpcReader::new(buf.clone())
+            .with_schema_overwrite(Some(overwrite))
+            .finish()?;
+        assert_eq!(df_read.column("a")?.dtype(), &DataType::Int64);
+        assert_eq!(df_read.column("b")?.dtype(), &DataType::Utf8);
+        assert!(df_read.frame_equal(&df!("a" => [1i64, 2, 3], "b" => ["x", "y", "z"])?));
+
+        let overwrite = Schema::from(vec![Field::new("not_there", DataType::Int64)]);
+        let out = IpcReader::new(buf)
+            .with_schema_overwrite(Some(overwrite))
+            .finish();
+        assert!(matches!(out, Err(PolarsError::NotFound(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_with_row_offset() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        // two record batches of 5 rows
+        let mut df = create_df();
+        df.vstack_mut(&create_df())?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+
+        let df_read = IpcReader::new(buf)
+            .with_row_offset(Some(3))
+            .with_n_rows(Some(4))
+            .with_row_count(Some(RowCount {
+                name: "row_nr".to_string(),
+                offset: 0,
+            }))
+            .finish()?;
+
+        let expected = df!(
+            "row_nr" => [3 as IdxSize, 4, 5, 6],
+            "days" => [3, 4, 0, 1],
+            "temp" => [2., 3., 22.1, 19.9]
+        )?;
+        assert!(df_read.frame_equal(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_metadata() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+        df.vstack_mut(&create_df())?;
+        IpcWriter::new(&mut buf)
+            .with_compression(Some(write::Compression::ZSTD))
+            .finish(&mut df)?;
+        buf.set_position(0);
+
+        let metadata = IpcReader::new(buf).metadata()?;
+        assert_eq!(metadata.num_batches, 2);
+        assert_eq!(metadata.num_rows, 10);
+        assert_eq!(metadata.batch_lengths, &[5, 5]);
+        assert!(matches!(
+            metadata.compression,
+            Some(write::Compression::ZSTD)
+        ));
+        assert_eq!(metadata.schema, df.schema().to_arrow());
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_parallel() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let columns = (0..20)
+            .map(|i| {
+                Series::new(
+                    &format!("c{}", i),
+                    (0..100).map(|v| v * i).collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let mut df = DataFrame::new(columns)?;
+        let chunk = df.clone();
+        df.vstack_mut(&chunk)?;
+        df.vstack_mut(&chunk)?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+
+        let serial = IpcReader::new(buf.clone())
+            .with_projection(Some(vec![12, 3, 7]))
+            .set_rechunk(false)
+            .finish()?;
+        let parallel = IpcReader::new(buf)
+            .with_projection(Some(vec![12, 3, 7]))
+            .with_parallel(true)
+            .set_rechunk(false)
+            .finish()?;
+        assert!(serial.frame_equal(&parallel));
+        assert_eq!(serial.n_chunks()?, parallel.n_chunks()?);
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_parallel_with_n_rows() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+        let chunk = df.clone();
+        df.vstack_mut(&chunk)?;
+        df.vstack_mut(&chunk)?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+
+        // corrupt the last block, which is not needed for the first 7 rows
+        let mut bytes = buf.into_inner();
+        let metadata = read::read_file_metadata(&mut Cursor::new(&bytes))?;
+        let offset = metadata.blocks[2].offset as usize;
+        bytes[offset + 4..offset + 8].copy_from_slice(&i32::MAX.to_le_bytes());
+
+        let (df_read, stats) = IpcReader::new(Cursor::new(bytes.clone()))
+            .with_parallel(true)
+            .with_n_rows(Some(7))
+            .finish_with_stats()?;
+        assert!(df_read.frame_equal(&df.head(Some(7))));
+        assert_eq!(stats.batches_read, 2);
+        assert!(!stats.reached_eof);
+
+        let (df_read, stats) = IpcReader::new(Cursor::new(bytes.clone()))
+            .with_parallel(true)
+            .with_n_rows(Some(10))
+            .finish_with_stats()?;
+        assert!(df_read.frame_equal(&df.head(Some(10))));
+        assert!(!stats.reached_eof);
+
+        assert!(IpcReader::new(Cursor::new(bytes))
+            .with_parallel(true)
+            .finish()
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn write_ipc_with_write_options() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+        IpcWriter::new(&mut buf)
+            .with_compression(Some(write::Compression::LZ4))
+            .with_write_options(Some(write::WriteOptions {
+                compression: Some(write::Compression::ZSTD),
+            }))
+            .finish(&mut df)?;
+        buf.set_position(0);
+
+        let metadata = IpcReader::new(buf.clone()).metadata()?;
+        assert!(matches!(
+            metadata.compression,
+            Some(write::Compression::ZSTD)
+        ));
+        let df_read = IpcReader::new(buf).finish()?;
+        assert!(df_read.frame_equal(&df));
+        Ok(())
+    }
+
+    #[test]
+    fn write_and_read_ipc_custom_metadata() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+        let metadata = BTreeMap::from([
+            ("producer".to_string(), "polars".to_string()),
+            ("dataset_id".to_string(), "42".to_string()),
+        ]);
+        IpcWriter::new(&mut buf)
+            .with_metadata(metadata.clone())
+            .finish(&mut df)?;
+        buf.set_position(0);
+
+        let mut reader = IpcReader::new(buf);
+        assert_eq!(reader.custom_metadata()?, metadata);
+        assert!(reader.finish()?.frame_equal(&df));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn write_ipc_dictionary_mode() -> Result<()> {
+        let mut df = df! {
+            "cat" => ["a", "b", "a", "c"],
+            "int" => [1, 2, 3, 4]
+        }?;
+        df.try_apply("cat", |s| s.cast(&DataType::Categorical(None)))?;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        IpcWriter::new(&mut buf)
+            .with_dictionaries_as(IpcDictionaryMode::Keep)
+            .finish(&mut df)?;
+        buf.set_position(0);
+        let df_read = IpcReader::new(buf).finish()?;
+        assert!(matches!(
+            df_read.column("cat")?.dtype(),
+            DataType::Categorical(_)
+        ));
+        assert!(df_read
+            .column("cat")?
+            .cast(&DataType::Utf8)?
+            .series_equal(&df.column("cat")?.cast(&DataType::Utf8)?));
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        IpcWriter::new(&mut buf)
+            .with_dictionaries_as(IpcDictionaryMode::Expand)
+            .finish(&mut df)?;
+        buf.set_position(0);
+        let df_read = IpcReader::new(buf).finish()?;
+        assert_eq!(df_read.column("cat")?.dtype(), &DataType::Utf8);
+        assert_eq!(
+            Vec::from(df_read.column("cat")?.utf8()?),
+            &[Some("a"), Some("b"), Some("a"), Some("c")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn write_ipc_categorical_round_trip() -> Result<()> {
+        use polars_core::{toggle_string_cache, SINGLE_LOCK};
+        let _lock = SINGLE_LOCK.lock();
+
+        // the categories in the order of the mapping
+        let categories = |s: &Series| -> Result<Vec<String>> {
+            let values = match &**s.categorical()?.get_rev_map() {
+                RevMapping::Local(values) => values,
+                RevMapping::Global(_, values, _) => values,
+            };
+            Ok(values.values_iter().map(|v| v.to_string()).collect())
+        };
+        let codes =
+            |s: &Series| -> Result<Vec<Option<u32>>> { Ok(Vec::from(s.categorical()?.logical())) };
+
+        for use_string_cache in [false, true] {
+            toggle_string_cache(use_string_cache);
+            let s = Series::new("cat", &["b", "a", "b", "c"]).cast(&DataType::Categorical(None))?;
+            // the order of occurrence differs from the order of the categories
+            let mut df = DataFrame::new(vec![s.slice(1, 3)])?;
+            let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+            IpcWriter::new(&mut buf).finish(&mut df)?;
+            buf.set_position(0);
+            let df_read = IpcReader::new(buf).finish()?;
+
+            let expected = df.column("cat")?;
+            let out = df_read.column("cat")?;
+            assert_eq!(categories(out)?, &["b", "a", "c"]);
+            assert_eq!(categories(out)?, categories(expected)?);
+            assert_eq!(codes(out)?, codes(expected)?);
+            assert!(out
+                .cast(&DataType::Utf8)?
+                .series_equal(&expected.cast(&DataType::Utf8)?));
+        }
+        toggle_string_cache(false);
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn read_ipc_async() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+
+        let df_read = IpcReaderAsync::new(Cursor::new(buf.into_inner()))
+            .with_columns(Some(vec!["temp".to_string()]))
+            .with_n_rows(Some(3))
+            .finish_async()
+            .await?;
+        assert!(df_read.frame_equal(&df.select(["temp"])?.head(Some(3))));
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_from_paths() -> Result<()> {
+        let dir = tempdir::TempDir::new("polars_ipc_parts")?;
+        let mut paths = vec![];
+        for i in 0..3 {
+            let path = dir.path().join(format!("part-{:04}.ipc", i));
+            let mut df = create_df();
+            IpcWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+            paths.push(path);
+        }
+
+        let df = IpcReader::from_paths(&paths)
+            .with_n_rows(Some(12))
+            .with_row_count(Some(RowCount {
+                name: "row_nr".to_string(),
+                offset: 0,
+            }))
+            .finish()?;
+        assert_eq!(df.height(), 12);
+        assert_eq!(
+            df.column("row_nr")?
+                .idx()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            (0..12).collect::<Vec<IdxSize>>()
+        );
+
+        let path = dir.path().join("other.ipc");
+        let mut other = df!("days" => [0.0f64], "temp" => [1.0f64])?;
+        IpcWriter::new(std::fs::File::create(&path)?).finish(&mut other)?;
+        paths.push(path);
+        let err = IpcReader::from_paths(&paths).finish().unwrap_err();
+        assert!(matches!(err, PolarsError::SchemaMisMatch(_)));
+        assert!(format!("{}", err).contains("\"days\""));
+        Ok(())
+    }
+
+    #[test]
+    fn ipc_schemas_compatible_checks_fields() -> Result<()> {
+        let schema = |fields: &[(&str, ArrowDataType)]| {
+            ArrowSchema::from(
+                fields
+                    .iter()
+                    .map(|(name, dtype)| ArrowField::new(*name, dtype.clone(), true))
+                    .collect::<Vec<_>>(),
+            )
+        };
+        let a = schema(&[("a", ArrowDataType::Int32), ("b", ArrowDataType::LargeUtf8)]);
+        ipc_schemas_compatible(&a, &a)?;
+        let mut not_nullable = a.clone();
+        not_nullable.fields[0].is_nullable = false;
+        ipc_schemas_compatible(&a, &not_nullable)?;
+
+        let msg = |a: &ArrowSchema, b: &ArrowSchema| match ipc_schemas_compatible(a, b) {
+            Err(PolarsError::SchemaMisMatch(msg)) => msg.to_string(),
+            _ => panic!("expected a SchemaMisMatch"),
+        };
+        let dtype = schema(&[("a", ArrowDataType::Int64), ("b", ArrowDataType::LargeUtf8)]);
+        let out = msg(&a, &dtype);
+        assert!(
+            out.contains("\"a\" has dtype Int32 in the first"),
+            "{}",
+            out
+        );
+        assert!(out.contains("Int64 in the second"), "{}", out);
+
+        let order = schema(&[("b", ArrowDataType::LargeUtf8), ("a", ArrowDataType::Int32)]);
+        let out = msg(&a, &order);
+        assert!(
+            out.contains("\"a\" is at position 0 in the first schema and at position 1"),
+            "{}",
+            out
+        );
+
+        let fewer = schema(&[("a", ArrowDataType::Int32)]);
+        assert!(msg(&a, &fewer).contains("\"b\" of the first schema is missing"));
+        assert!(msg(&fewer, &a).contains("\"b\" of the second schema is missing"));
+        Ok(())
+    }
+
+    #[test]
+    fn write_ipc_to_path_creates_dirs() -> Result<()> {
+        let dir = tempdir::TempDir::new("polars_ipc_to_path")?;
+        let mut df = create_df();
+
+        let path = write_ipc_to_path(
+            &mut df,
+            dir.path().join("nested").join("data"),
+            Some(IpcCompression::LZ4),
+        )?;
+        assert_eq!(path, dir.path().join("nested").join("data.ipc"));
+        let df_read = IpcReader::new(std::fs::File::open(&path)?).finish()?;
+        assert!(df_read.frame_equal(&df));
+
+        // an existing extension is kept
+        let path = write_ipc_to_path(&mut df, dir.path().join("data.arrow"), None)?;
+        assert_eq!(path, dir.path().join("data.arrow"));
+        assert!(path.exists());
+        Ok(())
+    }
+
+    #[test]
+    fn write_ipc_empty_frames() -> Result<()> {
+        // no columns: only the schema is written
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = DataFrame::new(vec![])?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+        assert_eq!(IpcReader::new(buf.clone()).metadata()?.num_batches, 0);
+        let df_read = IpcReader::new(buf).finish()?;
+        assert_eq!(df_read.shape(), (0, 0));
+
+        // no rows: a single empty record batch, also if there are multiple empty chunks
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!["a" => Vec::<i32>::new(), "b" => Vec::<&str>::new()]?;
+        let empty = df.clone();
+        df.vstack_mut(&empty)?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+        assert_eq!(IpcReader::new(buf.clone()).metadata()?.num_batches, 1);
+        let df_read = IpcReader::new(buf).finish()?;
+        assert_eq!(df_read.schema(), empty.schema());
+        assert!(df_read.frame_equal(&empty));
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_from_paths_row_count() -> Result<()> {
+        let dir = tempdir::TempDir::new("polars_ipc_row_count")?;
+        let mut first = create_df();
+        let mut second = create_df();
+        second.vstack_mut(&first)?;
+        let paths = vec![dir.path().join("a.ipc"), dir.path().join("b.ipc")];
+        IpcWriter::new(std::fs::File::create(&paths[0])?).finish(&mut first)?;
+        IpcWriter::new(std::fs::File::create(&paths[1])?).finish(&mut second)?;
+
+        let df = IpcReader::from_paths(&paths)
+            .with_columns(Some(vec!["temp".to_string()]))
+            .with_row_count(Some(RowCount {
+                name: "row_nr".to_string(),
+                offset: 10,
+            }))
+            .finish()?;
+        assert_eq!(df.get_column_names(), &["row_nr", "temp"]);
+        assert_eq!(
+            df.column("row_nr")?
+                .idx()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            (10..25).collect::<Vec<IdxSize>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_from_paths_sorted_merge() -> Result<()> {
+        let dir = tempdir::TempDir::new("polars_ipc_sorted_merge")?;
+        let mut first = df![
+            "key" => [1i64, 3, 5, 7],
+            "file" => ["a", "a", "a", "a"]
+        ]?;
+        let mut second = df![
+            "key" => [2i64, 3, 4, 10],
+            "file" => ["b", "b", "b", "b"]
+        ]?;
+        let paths = vec![dir.path().join("a.ipc"), dir.path().join("b.ipc")];
+        IpcWriter::new(std::fs::File::create(&paths[0])?).finish(&mut first)?;
+        IpcWriter::new(std::fs::File::create(&paths[1])?).finish(&mut second)?;
+
+        let df = IpcReader::from_paths(&paths)
+            .with_sorted_merge_on(Some("key".to_string()))
+            .finish()?;
+        assert_eq!(
+            Vec::from(df.column("key")?.i64()?),
+            &[1, 2, 3, 3, 4, 5, 7, 10].map(Some)
+        );
+        // equal keys keep the order of the files
+        assert_eq!(
+            Vec::from(df.column("file")?.utf8()?),
+            &["a", "b", "a", "b", "b", "a", "a", "b"].map(Some)
+        );
+
+        let df = IpcReader::from_paths(&paths)
+            .with_sorted_merge_on(Some("key".to_string()))
+            .with_n_rows(Some(3))
+            .finish()?;
+        assert_eq!(Vec::from(df.column("key")?.i64()?), &[1, 2, 3].map(Some));
+
+        let mut unsorted = df!["key" => [2i64, 1], "file" => ["c", "c"]]?;
+        let path = dir.path().join("c.ipc");
+        IpcWriter::new(std::fs::File::create(&path)?).finish(&mut unsorted)?;
+        let err = IpcReader::from_paths(&[paths[0].clone(), path])
+            .with_sorted_merge_on(Some("key".to_string()))
+            .finish();
+        assert!(err.is_err());
+
+        // null values sort first
+        let mut unsorted = df!["key" => [Some(1i64), None], "file" => ["d", "d"]]?;
+        let path = dir.path().join("d.ipc");
+        IpcWriter::new(std::fs::File::create(&path)?).finish(&mut unsorted)?;
+        let err = IpcReader::from_paths(&[paths[0].clone(), path])
+            .with_sorted_merge_on(Some("key".to_string()))
+            .finish();
+        assert!(err.is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn read_ipc_from_paths_sorted_merge_categorical() -> Result<()> {
+        use polars_core::{toggle_string_cache, SINGLE_LOCK};
+        let _lock = SINGLE_LOCK.lock();
+        toggle_string_cache(true);
+
+        let dir = tempdir::TempDir::new("polars_ipc_sorted_merge_categorical")?;
+        let mut first = df!["key" => ["b", "c"]]?;
+        let mut second = df!["key" => [None, Some("a"), Some("d")]]?;
+        let paths = vec![dir.path().join("a.ipc"), dir.path().join("b.ipc")];
+        for (df, path) in [&mut first, &mut second].into_iter().zip(&paths) {
+            df.try_apply("key", |s| s.cast(&DataType::Categorical(None)))?;
+            IpcWriter::new(std::fs::File::create(path)?).finish(df)?;
+        }
+
+        // the codes are b, c, a, d in the global cache, the merge follows the strings
+        let df = IpcReader::from_paths(&paths)
+            .with_sorted_merge_on(Some("key".to_string()))
+            .finish()?;
+        toggle_string_cache(false);
+        assert_eq!(
+            Vec::from(df.column("key")?.cast(&DataType::Utf8)?.utf8()?),
+            &[None, Some("a"), Some("b"), Some("c"), Some("d")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_projection_out_of_bounds() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+
+        let err = IpcReader::new(buf)
+            .with_projection(Some(vec![0, 99]))
+            .finish()
+            .unwrap_err();
+        assert!(matches!(err, PolarsError::ComputeError(_)));
+        assert!(format!("{}", err).contains("99"));
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_duplicate_projection() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+
+        buf.set_position(0);
+        let err = IpcReader::new(buf.clone())
+            .with_projection(Some(vec![1, 1, 0]))
+            .finish()
+            .unwrap_err();
+        assert!(matches!(err, PolarsError::ComputeError(_)));
+        assert!(format!("{}", err).contains("duplicate projection index 1"));
+
+        let err = IpcReader::new(buf)
+            .with_columns(Some(vec!["temp".to_string(), "temp".to_string()]))
+            .finish()
+            .unwrap_err();
+        assert!(matches!(err, PolarsError::ComputeError(_)));
+        assert!(format!("{}", err).contains("duplicate column name \"temp\""));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "lazy")]
+    fn read_ipc_skip_batches_with_statistics() -> Result<()> {
+        use crate::predicates::{BatchStats, PhysicalIoExpr, StatsEvaluator};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        // `days >= 2`, counts the batches it is evaluated on
+        struct DaysGtEq2(AtomicUsize);
+
+        impl PhysicalIoExpr for DaysGtEq2 {
+            fn evaluate(&self, df: &DataFrame) -> Result<Series> {
+                self.0.fetch_add(1, Ordering::Relaxed);
+                Ok(df.column("days")?.gt_eq(2i32)?.into_series())
+            }
+
+            fn as_stats_evaluator(&self) -> Option<&dyn StatsEvaluator> {
+                Some(self)
+            }
+
+            fn live_columns(&self) -> Option<Vec<String>> {
+                Some(vec!["days".to_string()])
+            }
+        }
+
+        impl StatsEvaluator for DaysGtEq2 {
+            fn should_read(&self, stats: &BatchStats) -> Result<bool> {
+                Ok(match stats.get_stats("days")?.to_min_max() {
+                    Some(min_max) => min_max.i32()?.get(1) >= Some(2),
+                    None => true,
+                })
+            }
+        }
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("days" => [0i32, 1], "temp" => [22.1, 19.9])?;
+        df.vstack_mut(&df!("days" => [2i32, 3], "temp" => [7.0, 2.0])?)?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+
+        for (statistics, evaluated) in [(true, 1), (false, 2)] {
+            buf.set_position(0);
+            let predicate = Arc::new(DaysGtEq2(AtomicUsize::new(0)));
+            let df_read = IpcReader::new(buf.clone())
+                .with_statistics(statistics)
+                .finish_with_scan_ops(
+                    Some(predicate.clone() as Arc<dyn PhysicalIoExpr>),
+                    None,
+                    None,
+                )?;
+            assert_eq!(predicate.0.load(Ordering::Relaxed), evaluated);
+            assert!(df_read.frame_equal(&df!("days" => [2i32, 3], "temp" => [7.0, 2.0])?));
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "lazy")]
+    fn read_ipc_scan_ops_unsupported_options() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+
+        let readers = [
+            IpcReader::new(buf.clone()).with_tail(Some(2)),
+            IpcReader::new(buf.clone()).with_batch(Some(0)),
+            IpcReader::new(buf.clone()).with_parallel(true),
+            IpcReader::new(buf.clone())
+                .with_rename(Some(vec![("days".to_string(), "day".to_string())])),
+            IpcReader::new(buf.clone()).with_output_schema_order(vec!["temp".to_string()]),
+            IpcReader::new(buf.clone()).with_assume_sorted(Some(vec!["days".to_string()])),
+            IpcReader::new(buf.clone()).with_coerce_large_types(true),
+            IpcReader::new(buf.clone()).with_flatten_structs(true),
+            IpcReader::new(buf.clone()).with_columns_lenient(vec!["days".to_string()], false),
+        ];
+        for reader in readers {
+            let err = reader.finish_with_scan_ops(None, None, None).unwrap_err();
+            assert!(matches!(err, PolarsError::InvalidOperation(_)));
+        }
+
+        let df_read = IpcReader::new(buf).finish_with_scan_ops(None, None, None)?;
+        assert!(df_read.frame_equal(&df));
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_with_rename() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2], "b" => [3, 4], "c" => [5, 6])?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+
+        let rename = |pairs: &[(&str, &str)]| {
+            Some(
+                pairs
+                    .iter()
+                    .map(|(old, new)| (old.to_string(), new.to_string()))
+                    .collect(),
+            )
+        };
+
+        buf.set_position(0);
+        let df_read = IpcReader::new(buf.clone())
+            .with_rename(rename(&[("a", "x"), ("c", "z")]))
+            .finish()?;
+        assert_eq!(df_read.get_column_names(), &["x", "b", "z"]);
+        assert_eq!(Vec::from(df_read.column("z")?.i32()?), &[Some(5), Some(6)]);
+
+        let df_read = IpcReader::new(buf.clone())
+            .with_rename(rename(&[("a", "b"), ("b", "a")]))
+            .finish()?;
+        assert_eq!(df_read.get_column_names(), &["b", "a", "c"]);
+
+        let out = IpcReader::new(buf.clone())
+            .with_rename(rename(&[("d", "x")]))
+            .finish();
+        assert!(matches!(out, Err(PolarsError::NotFound(_))));
+
+        let out = IpcReader::new(buf)
+            .with_rename(rename(&[("a", "x"), ("b", "x")]))
+            .finish();
+        assert!(matches!(out, Err(PolarsError::Duplicate(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_with_metadata() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+        let metadata = read::read_file_metadata(&mut buf)?;
+
+        // without the footer only the injected metadata can be used
+        let mut bytes = buf.into_inner();
+        bytes.truncate(bytes.len() - 6);
+        assert!(IpcReader::new(Cursor::new(bytes.clone())).finish().is_err());
+
+        let mut reader = IpcReader::new(Cursor::new(bytes)).with_metadata(metadata);
+        assert_eq!(reader.schema()?, df.schema());
+        assert!(reader.finish()?.frame_equal(&df));
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_for_each_batch() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+        let chunk = df.clone();
+        df.vstack_mut(&chunk)?;
+        df.vstack_mut(&chunk)?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+
+        let mut n_batches = 0;
+        let mut sum = 0.0;
+        IpcReader::new(buf.clone())
+            .with_columns(Some(vec!["temp".to_string()]))
+            .for_each_batch(|batch| {
+                assert_eq!(batch.get_column_names(), &["temp"]);
+                n_batches += 1;
+                sum += batch.column("temp")?.sum::<f64>().unwrap();
+                Ok(())
+            })?;
+        let full = IpcReader::new(buf.clone()).finish()?;
+        assert_eq!(n_batches, 3);
+        assert!((sum - full.column("temp")?.sum::<f64>().unwrap()).abs() < 1e-9);
+
+        // the last batch is sliced to `n_rows`
+        let mut heights = vec![];
+        IpcReader::new(buf.clone())
+            .with_n_rows(Some(7))
+            .with_row_count(Some(RowCount {
+                name: "rc".to_string(),
+                offset: 0,
+            }))
+            .for_each_batch(|batch| {
+                heights.push(batch.height());
+                assert_eq!(batch.get_column_names(), &["rc", "days", "temp"]);
+                Ok(())
+            })?;
+        assert_eq!(heights, &[5, 2]);
+
+        // errors of the callback are returned
+        let out =
+            IpcReader::new(buf).for_each_batch(|_| Err(PolarsError::ComputeError("stop".into())));
+        assert!(matches!(out, Err(PolarsError::ComputeError(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_assume_sorted() -> Result<()> {
+        use polars_core::series::IsSorted;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "days" => [0, 1, 2, 3, 4],
+            "flag" => [false, false, true, true, true]
+        )?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+
+        let df_read = IpcReader::new(buf.clone())
+            .with_assume_sorted(Some(vec!["days".to_string(), "flag".to_string()]))
+            .finish()?;
+        assert_eq!(df_read.column("days")?.is_sorted(), IsSorted::Ascending);
+        assert_eq!(df_read.column("flag")?.is_sorted(), IsSorted::Ascending);
+
+        let err = IpcReader::new(buf)
+            .with_assume_sorted(Some(vec!["unknown".to_string()]))
+            .finish()
+            .unwrap_err();
+        assert!(matches!(err, PolarsError::NotFound(_)));
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_schema_restores_position() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+
+        let mut reader = IpcReader::new(&mut buf);
+        assert_eq!(reader.schema()?, df.schema());
+        assert_eq!(reader.metadata()?.num_rows, df.height());
+        let df_read = reader.finish()?;
+        assert!(df_read.frame_equal(&df));
+
+        // the position before the call is restored, not only the start
+        buf.set_position(3);
+        let mut reader = IpcReader::new(&mut buf);
+        reader.arrow_schema()?;
+        reader.custom_metadata()?;
+        drop(reader);
+        assert_eq!(buf.position(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_coerce_large_types() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "a" => ["x", "y", "z"],
+            "b" => [
+                Series::new("", &["a", "b"]),
+                Series::new("", &["c"]),
+                Series::new("", &["d", "e", "f"])
+            ]
+        )?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+
+        // polars writes the large types
+        let schema = IpcReader::new(buf.clone()).arrow_schema()?;
+        assert_eq!(schema.fields[0].data_type(), &ArrowDataType::LargeUtf8);
+        assert!(matches!(
+            schema.fields[1].data_type(),
+            ArrowDataType::LargeList(_)
+        ));
+
+        let mut reader = IpcReader::new(buf.clone()).with_coerce_large_types(true);
+        let schema = reader.arrow_schema()?;
+        assert_eq!(schema.fields[0].data_type(), &ArrowDataType::Utf8);
+        match schema.fields[1].data_type() {
+            ArrowDataType::List(fld) => assert_eq!(fld.data_type(), &ArrowDataType::Utf8),
+            dt => panic!("expected a List, got {:?}", dt),
+        }
+        let df_read = reader.finish()?;
+        assert_eq!(df_read.column("a")?.dtype(), &DataType::Utf8);
+        assert!(df_read.frame_equal(&df));
+
+        let batches = IpcReader::new(buf)
+            .with_coerce_large_types(true)
+            .into_batches()?
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(batches.len(), 1);
+        assert!(batches[0].frame_equal(&df));
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_output_schema_order() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "a" => [1, 2, 3],
+            "b" => ["x", "y", "z"],
+            "c" => [true, false, true],
+            "d" => [1.0, 2.0, 3.0]
+        )?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+
+        let order = vec!["c".to_string(), "a".to_string(), "b".to_string()];
+        let df_read = IpcReader::new(buf.clone())
+            .with_output_schema_order(order.clone())
+            .finish()?;
+        assert_eq!(df_read.get_column_names(), &["c", "a", "b"]);
+        for (i, name) in order.iter().enumerate() {
+            assert!(df_read.get_columns()[i].series_equal(df.column(name)?));
+        }
+
+        // the projection is read in another order
+        let df_read = IpcReader::new(buf.clone())
+            .with_columns(Some(vec![
+                "b".to_string(),
+                "c".to_string(),
+                "a".to_string(),
+            ]))
+            .with_output_schema_order(order)
+            .finish()?;
+        assert_eq!(df_read.get_column_names(), &["c", "a", "b"]);
+
+        let err = IpcReader::new(buf)
+            .with_columns(Some(vec!["a".to_string()]))
+            .with_output_schema_order(vec!["a".to_string(), "b".to_string()])
+            .finish()
+            .unwrap_err();
+        assert!(matches!(err, PolarsError::NotFound(_)));
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_unsupported_dtype() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+
+        // fabricate metadata in which the second column has an extension type
+        let mut metadata = read::read_file_metadata(&mut buf)?;
+        metadata.schema.fields[1].data_type = ArrowDataType::Extension(
+            "uuid".to_string(),
+            Box::new(ArrowDataType::FixedSizeBinary(16)),
+            None,
+        );
+
+        let check = |result: Result<_>| match result {
+            Err(PolarsError::ComputeError(msg)) => {
+                assert!(msg.contains("\"temp\""), "{}", msg);
+                assert!(msg.contains("Extension"), "{}", msg);
+            }
+            _ => panic!("expected a ComputeError"),
+        };
+        check(
+            IpcReader::new(buf.clone())
+                .with_metadata(metadata.clone())
+                .schema()
+                .map(|_| ()),
+        );
+        check(
+            IpcReader::new(buf.clone())
+                .with_metadata(metadata.clone())
+                .finish()
+                .map(|_| ()),
+        );
+
+        // the column is not read, so it does not need to be supported
+        let df_read = IpcReader::new(buf)
+            .with_metadata(metadata)
+            .with_columns(Some(vec!["days".to_string()]))
+            .finish()?;
+        assert!(df_read.frame_equal(&df.select(["days"])?));
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_verify() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+        df.vstack_mut(&create_df())?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+
+        let df_read = IpcReader::new(buf.clone())
+            .with_verify(true)
+            .with_columns(Some(vec!["days".to_string()]))
+            .finish()?;
+        assert!(df_read.frame_equal(&df.select(["days"])?));
+
+        // corrupt the metadata length of the second record batch
+        let metadata = read::read_file_metadata(&mut buf)?;
+        let offset = metadata.blocks[1].offset as usize;
+        let mut bytes = buf.into_inner();
+        bytes[offset + 4] ^= 0x10;
+        let buf = Cursor::new(bytes);
+
+        match IpcReader::new(buf).with_verify(true).finish() {
+            Err(PolarsError::ComputeError(msg)) => {
+                assert!(msg.contains("block 1"), "{}", msg);
+                assert!(msg.contains("metadata length"), "{}", msg);
+            }
+            _ => panic!("expected a ComputeError"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn read_ipc_flatten_structs() -> Result<()> {
+        let point = StructChunked::new(
+            "point",
+            &[
+                Series::new("x", &[1i32, 2, 3]),
+                Series::new("y", &[Some(0.5f64), None, Some(1.5)]),
+            ],
+        )?
+        .into_series();
+        let mut df = DataFrame::new(vec![Series::new("id", &["a", "b", "c"]), point])?;
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+
+        let df_read = IpcReader::new(buf.clone())
+            .with_flatten_structs(true)
+            .finish()?;
+        assert_eq!(df_read.get_column_names(), &["id", "point.x", "point.y"]);
+        assert!(df_read
+            .column("point.x")?
+            .series_equal(&Series::new("", &[1i32, 2, 3])));
+        assert!(df_read
+            .column("point.y")?
+            .series_equal_missing(&Series::new("", &[Some(0.5f64), None, Some(1.5)])));
+
+        let df_read = IpcReader::new(buf).finish()?;
+        assert!(matches!(
+            df_read.column("point")?.dtype(),
+            DataType::Struct(_)
+        ));
+
+        // a flattened name that is already taken
+        let point = df.column("point")?.clone();
+        let mut df = DataFrame::new(vec![Series::new("point.x", &[0i32, 0, 0]), point])?;
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+        assert!(matches!(
+            IpcReader::new(buf).with_flatten_structs(true).finish(),
+            Err(PolarsError::Duplicate(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_column_statistics() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df![
+            "a" => [Some(3i32), Some(-1), None, Some(7), Some(5), Some(6)],
+            "b" => ["x", "y", "z", "x", "y", "z"],
+            "flag" => [Some(true), None, Some(true), Some(false), Some(false), None]
+        ]?;
+        IpcWriter::new(&mut buf)
+            .with_batch_size(Some(3))
+            .finish(&mut df)?;
+        buf.set_position(0);
+
+        let mut reader = IpcReader::new(buf);
+        let columns = ["flag".to_string(), "a".to_string()];
+        let stats = reader.column_statistics(&columns)?;
+        assert_eq!(stats.len(), 2);
+
+        let flag = stats[0].get_stats("flag")?;
+        assert_eq!(flag.null_count(), Some(1));
+        assert_eq!(flag.bool_counts(), Some((2, 0)));
+        let min_max = flag.to_min_max().unwrap();
+        assert_eq!(Vec::from(min_max.bool()?), &[Some(true), Some(true)]);
+        let flag = stats[1].get_stats("flag")?;
+        assert_eq!(flag.null_count(), Some(1));
+        assert_eq!(flag.bool_counts(), Some((0, 2)));
+
+        let a = stats[0].get_stats("a")?;
+        assert_eq!(a.null_count(), Some(1));
+        let min_max = a.to_min_max().unwrap();
+        assert_eq!(Vec::from(min_max.i32()?), &[Some(-1), Some(3)]);
+        let a = stats[1].get_stats("a")?;
+        assert_eq!(a.null_count(), Some(0));
+        let min_max = a.to_min_max().unwrap();
+        assert_eq!(Vec::from(min_max.i32()?), &[Some(5), Some(7)]);
+        assert!(stats[1].get_stats("b").is_err());
+
+        // the reader can be used afterwards
+        assert!(reader.finish()?.frame_equal_missing(&df));
+        Ok(())
+    }
+
+    /// Rewrite the record batch messages of an IPC file to the layout of arrow before 0.15,
+    /// without the continuation marker, with metadata `version`.
+    fn to_legacy_ipc(bytes: Vec<u8>, version: MetadataVersion) -> Result<Vec<u8>> {
+        use arrow_format::ipc::planus::{Builder, ReadAsRoot};
+        use arrow_format::ipc::{Message, MessageRef};
+
+        let mut buf = Cursor::new(bytes);
+        let metadata = read::read_file_metadata(&mut buf)?;
+        let mut bytes = buf.into_inner();
+        for block in &metadata.blocks {
+            let offset = block.offset as usize;
+            let meta_len = block.meta_data_length as usize;
+            assert_eq!(bytes[offset..offset + 4], CONTINUATION_MARKER);
+            let len = i32::from_le_bytes(bytes[offset + 4..offset + 8].try_into().unwrap());
+            let message = &bytes[offset + 8..offset + 8 + len as usize];
+            let mut message: Message = MessageRef::read_as_root(message)
+                .unwrap()
+                .try_into()
+                .unwrap();
+            message.version = version;
+            let mut builder = Builder::new();
+            let message = builder.finish(&message, None);
+
+            // the length prefix takes 4 bytes less, the remainder is padding
+            let legacy = &mut bytes[offset..offset + meta_len];
+            legacy.fill(0);
+            legacy[..4].copy_from_slice(&(meta_len as i32 - 4).to_le_bytes());
+            legacy[4..4 + message.len()].copy_from_slice(message);
+        }
+        Ok(bytes)
+    }
+
+    #[test]
+    fn read_ipc_with_rechunk_size() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let df = df![
+            "a" => (0..1000).collect::<Vec<i32>>(),
+            "b" => (0..1000).map(|v| v.to_string()).collect::<Vec<_>>()
+        ]?;
+        // the record batches of 250 rows don't line up with the chunks
+        let mut batches = split_by_batch_size(df.clone(), Some(250)).into_iter();
+        let mut written = batches.next().unwrap();
+        batches.try_for_each(|batch| written.vstack_mut(&batch).map(|_| ()))?;
+        IpcWriter::new(&mut buf).finish(&mut written)?;
+        buf.set_position(0);
+
+        for rechunk in [true, false] {
+            let df_read = IpcReader::new(buf.clone())
+                .set_rechunk(rechunk)
+                .with_rechunk_size(Some(300))
+                .finish()?;
+            assert!(df_read.frame_equal(&df));
+            for s in df_read.get_columns() {
+                assert_eq!(s.n_chunks(), 4);
+                assert_eq!(s.chunk_lengths().collect::<Vec<_>>(), [300, 300, 300, 100]);
+            }
+        }
+
+        let df_read = IpcReader::new(buf.clone())
+            .with_n_rows(Some(200))
+            .with_rechunk_size(Some(300))
+            .finish()?;
+        assert_eq!(df_read.n_chunks()?, 1);
+        assert!(df_read.frame_equal(&df.head(Some(200))));
+
+        let result = IpcReader::new(buf).with_rechunk_size(Some(0)).finish();
+        assert!(matches!(result, Err(PolarsError::ComputeError(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_with_footer() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+        df.vstack_mut(&create_df())?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        // fetch the footer separately, as with a ranged request
+        let footer_len =
+            i32::from_le_bytes(bytes[bytes.len() - 10..bytes.len() - 6].try_into().unwrap());
+        let footer = bytes[bytes.len() - 10 - footer_len as usize..].to_vec();
+        let file_len = bytes.len() as u64;
+
+        let mut reader =
+            IpcReader::new(Cursor::new(bytes.clone())).with_footer(footer.clone(), file_len);
+        assert_eq!(reader.metadata()?.num_rows, df.height());
+        assert!(reader.finish()?.frame_equal(&df));
+        let df_read = IpcReader::new(Cursor::new(bytes.clone()))
+            .with_footer(footer.clone(), file_len)
+            .with_columns(Some(vec!["days".to_string()]))
+            .finish()?;
+        assert!(df_read.frame_equal(&df.select(["days"])?));
+
+        // the footer doesn't contain the footer flatbuffer
+        let result = IpcReader::new(Cursor::new(bytes.clone()))
+            .with_footer(footer[footer.len() - 10..].to_vec(), file_len)
+            .finish();
+        assert!(result.is_err());
+        let result = IpcReader::new(Cursor::new(bytes))
+            .with_footer(footer, 4)
+            .finish();
+        assert!(matches!(result, Err(PolarsError::ComputeError(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_corrupt_message_length() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        let mut bytes = buf.into_inner();
+        let metadata = read::read_file_metadata(&mut Cursor::new(&bytes))?;
+        let offset = metadata.blocks[0].offset as usize;
+
+        for meta_len in [-8i32, i32::MAX] {
+            bytes[offset + 4..offset + 8].copy_from_slice(&meta_len.to_le_bytes());
+            match IpcReader::new(Cursor::new(bytes.clone())).finish() {
+                Err(PolarsError::ComputeError(msg)) => {
+                    assert!(msg.contains("metadata length"), "{}", msg)
+                }
+                _ => panic!("expected a ComputeError"),
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_legacy_format() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+        df.vstack_mut(&create_df())?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+
+        let bytes = buf.into_inner();
+        let legacy = to_legacy_ipc(bytes.clone(), MetadataVersion::V4)?;
+        let mut reader = IpcReader::new(Cursor::new(legacy.clone()));
+        assert_eq!(reader.metadata()?.num_rows, df.height());
+        assert!(reader.finish()?.frame_equal(&df));
+        let df_read = IpcReader::new(Cursor::new(legacy))
+            .with_verify(true)
+            .with_tail(Some(3))
+            .finish()?;
+        assert!(df_read.frame_equal(&df.tail(Some(3))));
+
+        let unsupported = to_legacy_ipc(bytes, MetadataVersion::V3)?;
+        match IpcReader::new(Cursor::new(unsupported)).finish() {
+            Err(PolarsError::ComputeError(msg)) => assert!(msg.contains("version V3"), "{}", msg),
+            _ => panic!("expected a ComputeError"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_with_limits() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+        df.vstack_mut(&create_df())?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+
+        let df_read = IpcReader::new(buf.clone())
+            .with_max_rows(df.height())
+            .with_max_bytes(buf.get_ref().len())
+            .finish()?;
+        assert!(df_read.frame_equal(&df));
+
+        // corrupt the body of the first record batch, the limit is checked before decoding
+        let metadata = read::read_file_metadata(&mut buf)?;
+        let block = &metadata.blocks[0];
+        let body_start = (block.offset + block.meta_data_length as i64) as usize;
+        let mut bytes = buf.into_inner();
+        for byte in &mut bytes[body_start..body_start + block.body_length as usize] {
+            *byte = 0xff;
+        }
+        let buf = Cursor::new(bytes);
+
+        match IpcReader::new(buf.clone())
+            .with_max_rows(df.height() - 1)
+            .finish()
+        {
+            Err(PolarsError::ComputeError(msg)) => {
+                assert!(msg.contains("exceeds the limit"), "{}", msg)
+            }
+            _ => panic!("expected a ComputeError"),
+        }
+        assert!(matches!(
+            IpcReader::new(buf).with_max_bytes(16).finish(),
+            Err(PolarsError::ComputeError(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_columns_lenient() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+
+        let columns = vec!["temp".to_string(), "added".to_string(), "days".to_string()];
+        let df_read = IpcReader::new(buf.clone())
+            .with_columns_lenient(columns.clone(), true)
+            .finish()?;
+        assert_eq!(df_read.get_column_names(), &["temp", "added", "days"]);
+        let added = df_read.column("added")?;
+        assert_eq!(added.dtype(), &DataType::Null);
+        assert_eq!(added.null_count(), df.height());
+        assert!(df_read
+            .select(["temp", "days"])?
+            .frame_equal(&df.select(["temp", "days"])?));
+
+        let df_read = IpcReader::new(buf.clone())
+            .with_columns_lenient(columns, false)
+            .finish()?;
+        assert_eq!(df_read.get_column_names(), &["temp", "days"]);
+
+        let err = IpcReader::new(buf)
+            .with_columns_lenient(vec!["added".to_string()], true)
+            .finish();
+        assert!(matches!(err, Err(PolarsError::NotFound(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_with_column_selector() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df![
+            "a" => [1i32, 2, 3],
+            "b" => ["x", "y", "z"],
+            "c" => [1i64, 2, 3],
+            "d" => [1.0f64, 2.0, 3.0]
+        ]?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+
+        let is_integer = || {
+            Box::new(|fld: &ArrowField| {
+                matches!(fld.data_type(), ArrowDataType::Int32 | ArrowDataType::Int64)
+            }) as Box<dyn Fn(&ArrowField) -> bool + Send>
+        };
+        let df_read = IpcReader::new(buf.clone())
+            .with_column_selector(is_integer())
+            .finish()?;
+        assert_eq!(df_read.get_column_names(), &["a", "c"]);
+        assert!(df_read.frame_equal(&df.select(["a", "c"])?));
+
+        let err = IpcReader::new(buf)
+            .with_column_selector(is_integer())
+            .with_projection(Some(vec![0]))
+            .finish();
+        assert!(err.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_with_shrink() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!["a" => vec!["abcdefgh"; 100]]?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+
+        // a sliced utf8 array keeps the values of the whole batch
+        let values_len = |df: &DataFrame| -> Result<usize> {
+            Ok(df
+                .column("a")?
+                .utf8()?
+                .downcast_iter()
+                .map(|arr| arr.values().len())
+                .sum())
+        };
+        let df_read = IpcReader::new(buf.clone())
+            .with_n_rows(Some(2))
+            .with_shrink(false)
+            .finish()?;
+        assert_eq!(df_read.height(), 2);
+        assert_eq!(values_len(&df_read)?, 800);
+
+        let df_read = IpcReader::new(buf).with_n_rows(Some(2)).finish()?;
+        assert!(df_read.frame_equal(&df.head(Some(2))));
+        assert_eq!(values_len(&df_read)?, 16);
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_into_batches() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+        let chunk = df.clone();
+        df.vstack_mut(&chunk)?;
+        df.vstack_mut(&chunk)?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+
+        let batches = IpcReader::new(buf.clone())
+            .into_batches()?
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(batches.len(), 3);
+        let mut out = batches[0].clone();
+        for batch in &batches[1..] {
+            out.vstack_mut(batch)?;
+        }
+        let expected = IpcReader::new(buf.clone()).finish()?;
+        assert!(out.frame_equal(&expected));
+
+        // `n_rows` caps the rows over all batches
+        let heights = IpcReader::new(buf.clone())
+            .with_n_rows(Some(7))
+            .into_batches()?
+            .map(|df| df.map(|df| df.height()))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(heights, &[5, 2]);
+
+        let first = IpcReader::new(buf.clone())
+            .with_columns(Some(vec!["temp".to_string()]))
+            .into_batches()?
+            .take(1)
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].get_column_names(), &["temp"]);
+
+        let readers = [
+            IpcReader::new(buf.clone()).with_tail(Some(10)),
+            IpcReader::new(buf.clone()).with_batch(Some(1)),
+            IpcReader::new(buf.clone()).with_parallel(true),
+            IpcReader::new(buf.clone()).with_rechunk_size(Some(4)),
+        ];
+        for reader in readers {
+            assert!(matches!(
+                reader.into_batches(),
+                Err(PolarsError::ComputeError(_))
+            ));
+        }
+        assert!(IpcReader::new(buf)
+            .with_tail(Some(10))
+            .for_each_batch(|_| Ok(()))
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_with_progress() -> Result<()> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+        let chunk = df.clone();
+        df.vstack_mut(&chunk)?;
+        df.vstack_mut(&chunk)?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_cb = calls.clone();
+        let df_read = IpcReader::new(buf.clone())
+            .with_progress(Box::new(move |done, total| {
+                let n = calls_cb.fetch_add(1, Ordering::Relaxed) + 1;
+                assert_eq!(done, n);
+                assert_eq!(total, 3);
+            }))
+            .finish()?;
+        assert!(df_read.frame_equal(&df));
+        assert_eq!(calls.load(Ordering::Relaxed), 3);
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_cb = calls.clone();
+        IpcReader::new(buf)
+            .with_progress(Box::new(move |_, _| {
+                calls_cb.fetch_add(1, Ordering::Relaxed);
+            }))
+            .for_each_batch(|_| Ok(()))?;
+        assert_eq!(calls.load(Ordering::Relaxed), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_single_batch() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+        let chunk = df.clone();
+        df.vstack_mut(&chunk)?;
+        df.vstack_mut(&chunk)?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+        assert_eq!(IpcReader::new(buf.clone()).metadata()?.num_batches, 3);
+
+        let df_read = IpcReader::new(buf.clone())
+            .with_batch(Some(1))
+            .with_columns(Some(vec!["days".to_string()]))
+            .with_row_count(Some(RowCount {
+                name: "rc".to_string(),
+                offset: 0,
+            }))
+            .finish()?;
+        assert_eq!(df_read.get_column_names(), &["rc", "days"]);
+        assert!(df_read.column("days")?.series_equal(chunk.column("days")?));
+        assert_eq!(
+            df_read
+                .column("rc")?
+                .idx()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            &[5, 6, 7, 8, 9]
+        );
+
+        match IpcReader::new(buf).with_batch(Some(3)).finish() {
+            Err(PolarsError::ComputeError(msg)) => {
+                assert!(msg.contains("has 3 batches"))
+            }
+            _ => panic!("expected a ComputeError"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_tail() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+        let chunk = df.clone();
+        df.vstack_mut(&chunk)?;
+        df.vstack_mut(&chunk)?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+        assert_eq!(IpcReader::new(buf.clone()).metadata()?.num_batches, 3);
+
+        let df_read = IpcReader::new(buf.clone())
+            .with_tail(Some(3))
+            .with_row_count(Some(RowCount {
+                name: "rc".to_string(),
+                offset: 0,
+            }))
+            .finish()?;
+        assert_eq!(df_read.shape(), (3, 3));
+        assert!(df_read.drop("rc")?.frame_equal(&df.tail(Some(3))));
+        assert_eq!(
+            df_read
+                .column("rc")?
+                .idx()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            &[12, 13, 14]
+        );
+
+        // the tail spans the boundary of two batches
+        let df_read = IpcReader::new(buf.clone()).with_tail(Some(7)).finish()?;
+        assert!(df_read.frame_equal(&df.tail(Some(7))));
+
+        let df_read = IpcReader::new(buf.clone()).with_tail(Some(100)).finish()?;
+        assert!(df_read.frame_equal(&df));
+
+        assert!(IpcReader::new(buf)
+            .with_tail(Some(3))
+            .with_n_rows(Some(2))
+            .finish()
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_with_stats() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = create_df();
+        let chunk = df.clone();
+        df.vstack_mut(&chunk)?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+
+        // (n_rows, rows_read, batches_read, reached_eof)
+        let cases = [
+            (None, 10, 2, true),
+            (Some(3), 3, 1, false),
+            (Some(5), 5, 1, false),
+            (Some(10), 10, 2, true),
+            (Some(20), 10, 2, true),
+        ];
+        for (n_rows, rows_read, batches_read, reached_eof) in cases {
+            buf.set_position(0);
+            let (df_read, stats) = IpcReader::new(buf.clone())
+                .with_n_rows(n_rows)
+                .finish_with_stats()?;
+            assert_eq!(df_read.height(), rows_read);
+            assert_eq!(
+                stats,
+                ReadStats {
+                    rows_read,
+                    batches_read,
+                    reached_eof
+                }
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn write_and_read_ipc_empty_series() {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let chunked_array = Float64Chunked::new("empty", &[0_f64; 0]);
+        let mut df = DataFrame::new(vec![chunked_array.into_series()]).unwrap();
+        IpcWriter::new(&mut buf)
+            .finish(&mut df)
+            .expect("ipc writer");
+
+        buf.set_position(0);
+
+        let df_read = IpcReader::new(buf).finish().unwrap();
+        assert!(df.frame_equal(&df_read));
+    }
+}
@@ -0,0 +1,1772 @@
+#[cfg(feature = "lazy")]
+use super::adapters::{predicate_projection, StatisticsReader};
+use super::adapters::{
+    CoerceReader, CountingReader, DecodedBatches, OffsetReader, ProgressReader, ReadStats,
+};
+use super::write::split_by_batch_size;
+use crate::aggregations::ScanAggregation;
+use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
+use crate::prelude::*;
+use crate::utils::apply_schema_overwrite;
+use crate::{finish_reader, ArrowReader, RowCount};
+use arrow::io::ipc::read;
+use polars_core::frame::ArrowChunk;
+use polars_core::prelude::*;
+use polars_core::POOL;
+use rayon::prelude::*;
+use std::collections::BTreeMap;
+use std::io::{Cursor, Read, Seek, SeekFrom};
+use std::sync::Arc;
+
+/// Read Arrows IPC format into a DataFrame
+///
+/// # Example
+/// ```
+/// use polars_core::prelude::*;
+/// use std::fs::File;
+/// use polars_io::ipc::IpcReader;
+/// use polars_io::SerReader;
+///
+/// fn example() -> Result<DataFrame> {
+///     let file = File::open("file.ipc").expect("file not found");
+///
+///     IpcReader::new(file)
+///         .finish()
+/// }
+/// ```
+#[must_use]
+pub struct IpcReader<R> {
+    /// File or Stream object
+    reader: R,
+    /// Aggregates chunks afterwards to a single chunk.
+    rechunk: bool,
+    n_rows: Option<usize>,
+    projection: Option<Vec<usize>>,
+    columns: Option<Vec<String>>,
+    row_count: Option<RowCount>,
+    schema_overwrite: Option<Schema>,
+    row_offset: usize,
+    parallel: bool,
+    statistics: bool,
+    batch: Option<usize>,
+    tail: Option<usize>,
+    metadata: Option<read::FileMetadata>,
+    footer: Option<(Vec<u8>, u64)>,
+    rename: Option<Vec<(String, String)>>,
+    assume_sorted: Option<Vec<String>>,
+    output_order: Option<Vec<String>>,
+    verify: bool,
+    progress: Option<Box<dyn Fn(usize, usize) + Send>>,
+    column_selector: Option<Box<dyn Fn(&ArrowField) -> bool + Send>>,
+    lenient_columns: Option<Vec<String>>,
+    fill_missing_columns: bool,
+    shrink: bool,
+    coerce_large_types: bool,
+    max_rows: Option<usize>,
+    max_bytes: Option<usize>,
+    flatten_structs: bool,
+    rechunk_size: Option<usize>,
+}
+
+impl<R: Read + Seek> IpcReader<R> {
+    /// Use `metadata` instead of reading the footer of the file again. The metadata must
+    /// belong to the file that is read, e.g. obtained with [`read::read_file_metadata`].
+    pub fn with_metadata(mut self, metadata: read::FileMetadata) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Parse the metadata from `footer` instead of seeking to the end of the file, e.g. when
+    /// the end of a file in an object store is fetched with a separate ranged request.
+    /// `footer` holds the last bytes of the file, at least the footer flatbuffer, its length
+    /// and the `ARROW1` magic, and `file_len` is the length of the complete file.
+    pub fn with_footer(mut self, footer: Vec<u8>, file_len: u64) -> Self {
+        self.footer = Some((footer, file_len));
+        self
+    }
+
+    /// Get the given metadata or read it from the footer of the file. The position of the
+    /// reader is restored afterwards.
+    fn read_metadata(&mut self) -> Result<read::FileMetadata> {
+        match (&self.metadata, &self.footer) {
+            (Some(metadata), _) => Ok(metadata.clone()),
+            (None, Some((footer, file_len))) => {
+                let mut reader = FooterReader::new(footer, *file_len)?;
+                Ok(read::read_file_metadata(&mut reader)?)
+            }
+            (None, None) => self.restore_position(|reader| Ok(read::read_file_metadata(reader)?)),
+        }
+    }
+
+    /// Call `f` with the reader and seek back to the current position afterwards, also if
+    /// `f` fails.
+    fn restore_position<T>(&mut self, f: impl FnOnce(&mut R) -> Result<T>) -> Result<T> {
+        let position = self.reader.stream_position()?;
+        let out = f(&mut self.reader);
+        self.reader.seek(SeekFrom::Start(position))?;
+        out
+    }
+
+    /// Get schema of the Ipc File.
+    ///
+    /// This and the other methods that read the footer, such as [`IpcReader::arrow_schema`]
+    /// and [`IpcReader::metadata`], seek back to the position of the reader before the
+    /// call, so `finish` can be called on the same reader afterwards.
+    pub fn schema(&mut self) -> Result<Schema> {
+        let metadata = self.read_metadata()?;
+        to_polars_schema(&metadata.schema)
+    }
+
+    /// Get arrow schema of the Ipc File, this is faster than creating a polars schema.
+    pub fn arrow_schema(&mut self) -> Result<ArrowSchema> {
+        let metadata = self.read_metadata()?;
+        if self.coerce_large_types {
+            Ok(coerce_large_schema(&metadata.schema))
+        } else {
+            Ok(metadata.schema)
+        }
+    }
+
+    /// Get the custom key-value metadata stored in the schema of the Ipc File.
+    /// See [`IpcWriter::with_metadata`].
+    pub fn custom_metadata(&mut self) -> Result<BTreeMap<String, String>> {
+        let metadata = self.read_metadata()?;
+        Ok(metadata.schema.metadata)
+    }
+
+    /// Get the metadata of the Ipc File. Only the footer and the headers of the record
+    /// batches are read, no column data is decoded.
+    pub fn metadata(&mut self) -> Result<IpcFileMetadata> {
+        let metadata = self.read_metadata()?;
+
+        let mut scratch = vec![];
+        let mut batch_lengths = Vec::with_capacity(metadata.blocks.len());
+        let mut compression = None;
+        self.restore_position(|reader| {
+            for block in &metadata.blocks {
+                let (length, batch_compression) =
+                    read_batch_header(reader, block.offset as u64, &mut scratch)?;
+                batch_lengths.push(length);
+                compression = compression.or(batch_compression);
+            }
+            Ok(())
+        })?;
+
+        Ok(IpcFileMetadata {
+            num_batches: batch_lengths.len(),
+            num_rows: batch_lengths.iter().sum(),
+            batch_lengths,
+            compression,
+            schema: metadata.schema,
+        })
+    }
+    /// Compute the statistics of `columns` for every record batch, e.g. to decide which
+    /// batches to read. IPC files don't store statistics, so only the given columns are
+    /// decoded to compute them, one batch at a time. For boolean columns the statistics also
+    /// hold the number of `true` and `false` values, see [`ColumnStats::bool_counts`].
+    ///
+    /// The position of the reader is restored afterwards.
+    pub fn column_statistics(&mut self, columns: &[String]) -> Result<Vec<BatchStats>> {
+        let metadata = self.read_metadata()?;
+        let projection = columns
+            .iter()
+            .map(|name| {
+                metadata
+                    .schema
+                    .fields
+                    .iter()
+                    .position(|fld| &fld.name == name)
+                    .ok_or_else(|| {
+                        PolarsError::NotFound(format!("column {:?} is not in the IPC file", name))
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // the arrays of a batch are decoded in the order of the file
+        let mut sorted_projection = projection.clone();
+        sorted_projection.sort_unstable();
+        sorted_projection.dedup();
+
+        self.restore_position(|reader| {
+            let dictionaries = read::read_file_dictionaries(reader, &metadata)?;
+            let mut scratch = vec![];
+            let mut batch_stats = Vec::with_capacity(metadata.blocks.len());
+            for idx in 0..metadata.blocks.len() {
+                let arrays = read::read_batch(
+                    reader,
+                    &dictionaries,
+                    &metadata,
+                    Some(&sorted_projection),
+                    idx,
+                    &mut scratch,
+                )?
+                .into_arrays();
+                let mut schema = Schema::with_capacity(columns.len());
+                let mut stats = Vec::with_capacity(columns.len());
+                for (i, name) in projection.iter().zip(columns) {
+                    let arr = arrays[sorted_projection.binary_search(i).unwrap()].clone();
+                    let s = Series::try_from((name.as_str(), arr))?;
+                    schema.with_column(name.clone(), s.dtype().clone());
+                    stats.push(ColumnStats::from_series(&s));
+                }
+                batch_stats.push(BatchStats::new(schema, stats));
+            }
+            Ok(batch_stats)
+        })
+    }
+
+    /// Stop reading when `n` rows are read.
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Columns to select/ project. Selecting the same column twice results in an error.
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Add a `row_count` column.
+    pub fn with_row_count(mut self, row_count: Option<RowCount>) -> Self {
+        self.row_count = row_count;
+        self
+    }
+
+    /// Set the reader's column projection. This counts from 0, meaning that
+    /// `vec![0, 4]` would select the 1st and 5th column. Duplicate indices are not
+    /// deduplicated but result in an error.
+    pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Select the columns for which `selector` returns `true`, evaluated against the fields of
+    /// the file's arrow schema. The columns keep the order of the file.
+    ///
+    /// This cannot be combined with [`IpcReader::with_columns`] or
+    /// [`IpcReader::with_projection`].
+    pub fn with_column_selector(
+        mut self,
+        selector: Box<dyn Fn(&ArrowField) -> bool + Send>,
+    ) -> Self {
+        self.column_selector = Some(selector);
+        self
+    }
+
+    /// Columns to select, where columns that are not in the file don't result in an error.
+    /// This allows reading files that were written before a column was added with the same
+    /// options. At least one of the columns must be in the file.
+    ///
+    /// If `fill_missing` is `true`, a missing column is added as a column of null values of
+    /// dtype [`DataType::Null`] and the columns are in the order of `columns`. Otherwise the
+    /// missing columns are skipped.
+    ///
+    /// This cannot be combined with [`IpcReader::with_columns`],
+    /// [`IpcReader::with_projection`] or [`IpcReader::with_column_selector`].
+    pub fn with_columns_lenient(mut self, columns: Vec<String>, fill_missing: bool) -> Self {
+        self.lenient_columns = Some(columns);
+        self.fill_missing_columns = fill_missing;
+        self
+    }
+
+    /// Shrink the columns to fit their length if [`IpcReader::with_n_rows`] sliced off the
+    /// tail of a record batch, so the memory of the rows that are not read is freed. This
+    /// copies the columns into a single chunk, so it is skipped if rechunking is turned off
+    /// with [`SerReader::set_rechunk`]. Defaults to `true`.
+    pub fn with_shrink(mut self, shrink: bool) -> Self {
+        self.shrink = shrink;
+        self
+    }
+
+    /// Split the columns into chunks of at most `rechunk_size` rows after the record batches
+    /// are decoded, instead of the chunks that mirror the record batches or a single chunk.
+    /// The last chunk may be shorter. This takes precedence over [`SerReader::set_rechunk`],
+    /// the columns are never copied into a single chunk when it is set, so the shrinking of
+    /// [`IpcReader::with_shrink`] is skipped as well. Defaults to `None`.
+    pub fn with_rechunk_size(mut self, rechunk_size: Option<usize>) -> Self {
+        self.rechunk_size = rechunk_size;
+        self
+    }
+
+    /// Coerce `LargeUtf8`, `LargeBinary` and `LargeList` arrays, also nested ones, to `Utf8`,
+    /// `Binary` and `List` while the record batches are decoded. An array with offsets that
+    /// don't fit in `i32` results in an error. [`IpcReader::arrow_schema`] reports the coerced
+    /// types. The dtypes of the `DataFrame` are `Utf8` and `List` either way. Defaults to
+    /// `false`.
+    pub fn with_coerce_large_types(mut self, coerce: bool) -> Self {
+        self.coerce_large_types = coerce;
+        self
+    }
+
+    /// Replace every struct column with a column per field, named `parent.child`. Nested
+    /// structs are flattened recursively, e.g. to `a.b.c`. A flattened name that is already
+    /// taken results in an error. The names passed to [`IpcReader::with_rename`] and the
+    /// other options that apply to the result refer to the flattened columns. Defaults to
+    /// `false`.
+    pub fn with_flatten_structs(mut self, flatten: bool) -> Self {
+        self.flatten_structs = flatten;
+        self
+    }
+
+    /// Rename columns of the resulting `DataFrame`, given as `(old, new)` pairs. The names
+    /// are replaced at once, so columns can be swapped. Renaming a column that is not read
+    /// or renaming to a name that occurs more than once afterwards results in an error.
+    pub fn with_rename(mut self, rename: Option<Vec<(String, String)>>) -> Self {
+        self.rename = rename;
+        self
+    }
+
+    /// Flag the columns named in `columns` as sorted in ascending order, so operations on the
+    /// result can use their fast paths for sorted data without checking the data first.
+    /// The names refer to the columns of the result, i.e. after renaming.
+    ///
+    /// # Warning
+    /// This is not checked. If the columns are not sorted, operations that rely on the flag
+    /// return wrong results.
+    pub fn with_assume_sorted(mut self, columns: Option<Vec<String>>) -> Self {
+        self.assume_sorted = columns;
+        self
+    }
+
+    /// Return exactly the columns named in `columns` in that order, whatever the order in
+    /// the file or of the projection. The names refer to the columns of the result, i.e.
+    /// after renaming, and include the row count column if it should be kept. A name that is
+    /// not in the result is an error. This can be combined with [`IpcReader::with_columns`]
+    /// to read a subset of the columns in a fixed positional layout.
+    pub fn with_output_schema_order(mut self, columns: Vec<String>) -> Self {
+        self.output_order = Some(columns);
+        self
+    }
+
+    /// Overwrite the dtypes of the columns in the file. The columns named in `schema` are cast
+    /// to the given dtype while the record batches are read. Other columns keep their dtype.
+    pub fn with_schema_overwrite(mut self, schema: Option<Schema>) -> Self {
+        self.schema_overwrite = schema;
+        self
+    }
+
+    /// Start reading at row `offset`. Record batches that lie completely before the offset are
+    /// skipped, the batch that contains the offset is sliced. Combined with
+    /// [`IpcReader::with_n_rows`], at most `n_rows` rows after the offset are read.
+    ///
+    /// A `row_count` column counts from the absolute position in the file, so the first
+    /// row will have the value `row_count.offset + offset`.
+    pub fn with_row_offset(mut self, offset: Option<usize>) -> Self {
+        self.row_offset = offset.unwrap_or(0);
+        self
+    }
+
+    /// Decode the record batches in parallel. To be able to do so, the whole file is
+    /// read into memory before decoding. Defaults to `false`.
+    pub fn with_parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    /// Use batch statistics to skip the record batches that cannot match the predicate
+    /// given to [`IpcReader::finish_with_scan_ops`]. Defaults to `false`.
+    ///
+    /// IPC files don't store statistics, so the min/max values are computed by only
+    /// decoding the columns the predicate depends on. The other columns of a batch are
+    /// only decoded if the statistics don't rule out a match. Statistics are not used if
+    /// the predicate does not support them or if a row count or row offset is set.
+    pub fn with_statistics(mut self, statistics: bool) -> Self {
+        self.statistics = statistics;
+        self
+    }
+
+    /// Only read the record batch with index `batch`; the other batches are not decoded.
+    /// The number of batches in the file can be found with [`IpcReader::metadata`].
+    ///
+    /// The projection, row offset and `n_rows` are applied to the selected batch. A
+    /// `row_count` column counts from the position of the batch in the file.
+    pub fn with_batch(mut self, batch: Option<usize>) -> Self {
+        self.batch = batch;
+        self
+    }
+
+    /// Only read the last `n` rows of the file. The number of rows is computed from the
+    /// headers of the record batches, batches that lie completely before the last `n` rows
+    /// are not decoded and the first decoded batch is sliced.
+    ///
+    /// A `row_count` column counts from the absolute position in the file. Combining this
+    /// with [`IpcReader::with_n_rows`], [`IpcReader::with_row_offset`] or
+    /// [`IpcReader::with_batch`] results in an error.
+    pub fn with_tail(mut self, n: Option<usize>) -> Self {
+        self.tail = n;
+        self
+    }
+
+    /// Decode the record batches that contain the last `n` rows. Returns the batches, the
+    /// number of rows in the file before the last `n` rows and the number of rows to skip
+    /// in the first batch.
+    fn read_tail_batches(
+        &mut self,
+        metadata: &read::FileMetadata,
+        n: usize,
+        projection: Option<&[usize]>,
+    ) -> Result<(Vec<ArrowChunk>, usize, usize)> {
+        let mut scratch = vec![];
+        let mut batch_lengths = Vec::with_capacity(metadata.blocks.len());
+        for block in &metadata.blocks {
+            let (length, _) =
+                read_batch_header(&mut self.reader, block.offset as u64, &mut scratch)?;
+            batch_lengths.push(length);
+        }
+        let total: usize = batch_lengths.iter().sum();
+        let start = total.saturating_sub(n);
+
+        // find the first batch that contains rows at or after `start`
+        let mut rows_before = 0;
+        let mut first = batch_lengths.len();
+        for (i, length) in batch_lengths.iter().enumerate() {
+            if rows_before + length > start {
+                first = i;
+                break;
+            }
+            rows_before += length;
+        }
+
+        let dictionaries = read::read_file_dictionaries(&mut self.reader, metadata)?;
+        let batches = (first..metadata.blocks.len())
+            .map(|batch| {
+                read::read_batch(
+                    &mut self.reader,
+                    &dictionaries,
+                    metadata,
+                    projection,
+                    batch,
+                    &mut scratch,
+                )
+                .map_err(PolarsError::from)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok((batches, start, start - rows_before))
+    }
+
+    /// Check the integrity of the file before reading it. Every record batch is checked
+    /// against the footer and fully decoded, even if only a subset of the columns or rows
+    /// is read afterwards. The first inconsistency results in an error that names the
+    /// index of the block. Defaults to `false`.
+    pub fn with_verify(mut self, verify: bool) -> Self {
+        self.verify = verify;
+        self
+    }
+
+    /// Refuse to read files with more than `max_rows` rows. The number of rows is taken from
+    /// the headers of the record batches, so a file over the limit results in an error
+    /// before any column data is decoded. Unlike [`IpcReader::with_n_rows`], the file is
+    /// not read partially.
+    pub fn with_max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = Some(max_rows);
+        self
+    }
+
+    /// Refuse to read files whose record batches take up more than `max_bytes` bytes, as
+    /// recorded by the blocks in the footer. This is the size in the file, the size in
+    /// memory may be larger for compressed files.
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Check the size of the file against the limits given by [`IpcReader::with_max_rows`]
+    /// and [`IpcReader::with_max_bytes`] without decoding any record batch.
+    fn check_limits(&mut self, metadata: &read::FileMetadata) -> Result<()> {
+        if let Some(max_bytes) = self.max_bytes {
+            let n_bytes: i64 = metadata
+                .blocks
+                .iter()
+                .map(|block| block.meta_data_length as i64 + block.body_length)
+                .sum();
+            if n_bytes > max_bytes as i64 {
+                return Err(PolarsError::ComputeError(
+                    format!(
+                        "the record batches of the IPC file take up {} bytes, which exceeds the limit of {} bytes",
+                        n_bytes, max_bytes
+                    )
+                    .into(),
+                ));
+            }
+        }
+        if let Some(max_rows) = self.max_rows {
+            let mut scratch = vec![];
+            let n_rows = self.restore_position(|reader| {
+                let mut n_rows = 0;
+                for block in &metadata.blocks {
+                    n_rows += read_batch_header(reader, block.offset as u64, &mut scratch)?.0;
+                }
+                Ok(n_rows)
+            })?;
+            if n_rows > max_rows {
+                return Err(PolarsError::ComputeError(
+                    format!(
+                        "the IPC file has {} rows, which exceeds the limit of {} rows",
+                        n_rows, max_rows
+                    )
+                    .into(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that the record batches use a metadata version that can be decoded. Files written
+    /// by arrow 0.8 and later use version 4 or 5; the files of versions before arrow 0.15
+    /// don't have a continuation marker in front of the messages, which is detected when the
+    /// messages are read. Only the first record batch is checked, as a file is written with a
+    /// single version.
+    fn check_metadata_version(&mut self, metadata: &read::FileMetadata) -> Result<()> {
+        use arrow_format::ipc::MetadataVersion;
+
+        let block = match metadata.blocks.first() {
+            Some(block) => block,
+            None => return Ok(()),
+        };
+        let mut scratch = vec![];
+        let version = self.restore_position(|reader| {
+            read_metadata_version(reader, block.offset as u64, &mut scratch)
+        })?;
+        match version {
+            MetadataVersion::V4 | MetadataVersion::V5 => Ok(()),
+            version => Err(PolarsError::ComputeError(
+                format!(
+                    "the IPC file has metadata version {:?}, only versions V4 (arrow 0.8 and later) and V5 (arrow 1.0 and later) can be read",
+                    version
+                )
+                .into(),
+            )),
+        }
+    }
+
+    /// Call `progress` with `(batches_done, batches_total)` after every record batch that is
+    /// decoded. The total is the number of batches that will be decoded, which is the number
+    /// of blocks in the footer unless a single batch or the tail is read. If reading stops
+    /// early because of [`IpcReader::with_n_rows`], the total is not reached.
+    ///
+    /// The callback runs on the thread that decodes the batches and blocks decoding while it
+    /// runs, so it should be cheap and must not block.
+    pub fn with_progress(mut self, progress: Box<dyn Fn(usize, usize) + Send>) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Check that the blocks in the footer point to record batches that lie within the
+    /// file, have the metadata length the footer records and can be decoded.
+    fn verify_blocks(&mut self, metadata: &read::FileMetadata) -> Result<()> {
+        let file_len = self.reader.seek(SeekFrom::End(0))?;
+        let corrupt = |idx: usize, msg: String| {
+            PolarsError::ComputeError(
+                format!("block {} of the IPC file is corrupt: {}", idx, msg).into(),
+            )
+        };
+
+        let dictionaries =
+            read::read_file_dictionaries(&mut self.reader, metadata).map_err(|e| {
+                PolarsError::ComputeError(
+                    format!("the dictionaries of the IPC file are corrupt: {}", e).into(),
+                )
+            })?;
+        let mut scratch = vec![];
+        for (idx, block) in metadata.blocks.iter().enumerate() {
+            let end = block.offset + block.meta_data_length as i64 + block.body_length;
+            if block.offset < 0
+                || block.meta_data_length < 0
+                || block.body_length < 0
+                || end as u64 > file_len
+            {
+                return Err(corrupt(
+                    idx,
+                    format!(
+                        "the block at offset {} with length {} does not fit in the file of {} bytes",
+                        block.offset,
+                        block.meta_data_length as i64 + block.body_length,
+                        file_len
+                    ),
+                ));
+            }
+
+            self.reader.seek(SeekFrom::Start(block.offset as u64))?;
+            let mut buf = [0u8; 4];
+            self.reader.read_exact(&mut buf)?;
+            let mut prefix_len = 4;
+            if buf == CONTINUATION_MARKER {
+                self.reader.read_exact(&mut buf)?;
+                prefix_len += 4;
+            }
+            let meta_len = i32::from_le_bytes(buf) as i64 + prefix_len;
+            if meta_len != block.meta_data_length as i64 {
+                return Err(corrupt(
+                    idx,
+                    format!(
+                        "the message has a metadata length of {} bytes, the footer records {}",
+                        meta_len, block.meta_data_length
+                    ),
+                ));
+            }
+
+            read::read_batch(
+                &mut self.reader,
+                &dictionaries,
+                metadata,
+                None,
+                idx,
+                &mut scratch,
+            )
+            .map_err(|e| corrupt(idx, format!("the record batch cannot be decoded: {}", e)))?;
+        }
+        Ok(())
+    }
+
+    /// Decode only the record batch at index `batch` and return it together with the
+    /// number of rows in the batches before it.
+    fn read_single_batch(
+        &mut self,
+        metadata: &read::FileMetadata,
+        batch: usize,
+        projection: Option<&[usize]>,
+    ) -> Result<(ArrowChunk, usize)> {
+        if batch >= metadata.blocks.len() {
+            return Err(PolarsError::ComputeError(
+                format!(
+                    "batch index {} is out of bounds, the IPC file has {} batches",
+                    batch,
+                    metadata.blocks.len()
+                )
+                .into(),
+            ));
+        }
+        let mut scratch = vec![];
+        let mut rows_before = 0;
+        for block in &metadata.blocks[..batch] {
+            let (length, _) =
+                read_batch_header(&mut self.reader, block.offset as u64, &mut scratch)?;
+            rows_before += length;
+        }
+
+        let dictionaries = read::read_file_dictionaries(&mut self.reader, metadata)?;
+        let chunk = read::read_batch(
+            &mut self.reader,
+            &dictionaries,
+            metadata,
+            projection,
+            batch,
+            &mut scratch,
+        )?;
+        Ok((chunk, rows_before))
+    }
+
+    /// Decode the record batches on the thread pool. The batches are returned in file order.
+    /// Only the blocks of the batches up to `n_rows` after the row offset are read, one
+    /// byte range per block, and decoded.
+    fn read_batches_parallel(
+        &mut self,
+        metadata: &read::FileMetadata,
+        projection: Option<&[usize]>,
+    ) -> Result<Vec<ArrowChunk>> {
+        let mut scratch = vec![];
+        let n_blocks = match self.n_rows {
+            Some(n_rows) => {
+                let rows_needed = self.row_offset.saturating_add(n_rows);
+                let mut rows = 0;
+                let mut n_blocks = 0;
+                for block in &metadata.blocks {
+                    if rows >= rows_needed {
+                        break;
+                    }
+                    let (length, _) =
+                        read_batch_header(&mut self.reader, block.offset as u64, &mut scratch)?;
+                    rows += length;
+                    n_blocks += 1;
+                }
+                n_blocks
+            }
+            None => metadata.blocks.len(),
+        };
+
+        let file_len = self.reader.seek(SeekFrom::End(0))?;
+        let blocks = metadata.blocks[..n_blocks]
+            .iter()
+            .map(|block| {
+                let len = block.meta_data_length as i128 + block.body_length as i128;
+                if block.offset < 0 || len < 0 || block.offset as i128 + len > file_len as i128 {
+                    return Err(PolarsError::ComputeError(
+                        format!(
+                            "IPC block at offset {} of {} bytes exceeds the file of {} bytes",
+                            block.offset, len, file_len
+                        )
+                        .into(),
+                    ));
+                }
+                let mut bytes = vec![0; len as usize];
+                self.reader.seek(SeekFrom::Start(block.offset as u64))?;
+                self.reader.read_exact(&mut bytes)?;
+                Ok((block.offset as u64, bytes))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let dictionaries = read::read_file_dictionaries(&mut self.reader, metadata)?;
+        POOL.install(|| {
+            blocks
+                .par_iter()
+                .enumerate()
+                .map_init(Vec::new, |scratch, (idx, (offset, bytes))| {
+                    let mut reader = BlockReader::new(bytes, *offset, file_len);
+                    read::read_batch(
+                        &mut reader,
+                        &dictionaries,
+                        metadata,
+                        projection,
+                        idx,
+                        scratch,
+                    )
+                    .map_err(PolarsError::from)
+                })
+                .collect()
+        })
+    }
+
+    /// Read the IPC file into a DataFrame and return statistics about the read. This can be
+    /// used to detect if the end of the file is reached when reading with
+    /// [`IpcReader::with_n_rows`].
+    pub fn finish_with_stats(self) -> Result<(DataFrame, ReadStats)> {
+        self.finish_impl(true)
+    }
+
+    /// Validate the options against the file's schema and resolve the projection. Returns the
+    /// sorted projection and the schema of the projected batches.
+    fn resolve_projection(
+        &mut self,
+        metadata: &read::FileMetadata,
+    ) -> Result<(Option<Vec<usize>>, ArrowSchema)> {
+        let schema = &metadata.schema;
+        check_schema_overwrite(self.schema_overwrite.as_ref(), schema)?;
+
+        if let Some(columns) = &self.lenient_columns {
+            if self.columns.is_some() || self.projection.is_some() || self.column_selector.is_some()
+            {
+                return Err(PolarsError::ComputeError(
+                    "lenient columns cannot be combined with 'columns', a projection or a column selector".into(),
+                ));
+            }
+            let present = columns
+                .iter()
+                .filter(|name| schema.fields.iter().any(|fld| &fld.name == *name))
+                .cloned()
+                .collect::<Vec<_>>();
+            if present.is_empty() {
+                return Err(PolarsError::NotFound(format!(
+                    "none of the columns {:?} are in the IPC file",
+                    columns
+                )));
+            }
+            self.columns = Some(present);
+        }
+        if let Some(selector) = self.column_selector.take() {
+            if self.columns.is_some() || self.projection.is_some() {
+                return Err(PolarsError::ComputeError(
+                    "a column selector cannot be combined with 'columns' or a projection".into(),
+                ));
+            }
+            let prj = schema
+                .fields
+                .iter()
+                .enumerate()
+                .filter_map(|(i, fld)| selector(fld).then(|| i))
+                .collect();
+            self.projection = Some(prj);
+        }
+        if let Some(columns) = self.columns.take() {
+            check_columns(&columns)?;
+            let prj = columns_to_projection(columns, schema)?;
+            self.projection = Some(prj);
+        }
+        if let Some(projection) = &self.projection {
+            check_projection(projection, schema)?;
+        }
+
+        let sorted_projection = self.projection.clone().map(|mut proj| {
+            proj.sort_unstable();
+            proj
+        });
+
+        let schema = if let Some(projection) = &sorted_projection {
+            apply_projection(&metadata.schema, projection)
+        } else {
+            metadata.schema.clone()
+        };
+        // fail before decoding if a column that is read cannot be represented
+        to_polars_schema(&schema)?;
+        Ok((sorted_projection, schema))
+    }
+
+    /// Decode the record batches one at a time and pass each of them as a `DataFrame` to `f`.
+    /// Only a single decoded batch is held in memory, which allows folding over files that
+    /// don't fit in memory.
+    ///
+    /// The projection, row offset, `row_count` and schema overwrite are applied to every
+    /// batch. Iteration stops once `n_rows` rows are passed to `f`, the last batch is sliced
+    /// if needed. An error returned by `f` stops the iteration and is returned. The options
+    /// that [`IpcReader::into_batches`] rejects result in an error as well.
+    pub fn for_each_batch<F>(self, mut f: F) -> Result<()>
+    where
+        F: FnMut(DataFrame) -> Result<()>,
+    {
+        for df in self.into_batches()? {
+            f(df?)?;
+        }
+        Ok(())
+    }
+
+    /// Get an iterator that decodes the record batches one at a time and yields each of
+    /// them as a `DataFrame`, with the same options applied as in
+    /// [`IpcReader::for_each_batch`]. A batch is only decoded when the next item is
+    /// requested, so adapters such as `take` stop reading early.
+    ///
+    /// The iterator stops once `n_rows` rows are yielded or after the first error.
+    ///
+    /// [`IpcReader::with_tail`], [`IpcReader::with_batch`], [`IpcReader::with_parallel`] and
+    /// [`IpcReader::with_rechunk_size`] decide which batches are read or how the rows are
+    /// chunked over the whole file, so setting any of them results in an error.
+    pub fn into_batches(mut self) -> Result<impl Iterator<Item = Result<DataFrame>>> {
+        let unsupported = [
+            (self.tail.is_some(), "'tail'"),
+            (self.batch.is_some(), "a batch index"),
+            (self.parallel, "parallel reading"),
+            (self.rechunk_size.is_some(), "a rechunk size"),
+        ];
+        if let Some((_, option)) = unsupported.iter().find(|(set, _)| *set) {
+            return Err(PolarsError::ComputeError(
+                format!(
+                    "{} cannot be combined with reading the record batches one at a time",
+                    option
+                )
+                .into(),
+            ));
+        }
+        let metadata = self.read_metadata()?;
+        self.check_metadata_version(&metadata)?;
+        self.check_limits(&metadata)?;
+        if self.verify {
+            self.verify_blocks(&metadata)?;
+        }
+        let (sorted_projection, schema) = self.resolve_projection(&metadata)?;
+
+        let row_count = self.absolute_row_count();
+        let n_blocks = metadata.blocks.len();
+        let reader = CoerceReader::new(
+            OffsetReader::new(
+                ProgressReader::new(
+                    read::FileReader::new(self.reader, metadata, sorted_projection),
+                    n_blocks,
+                    self.progress,
+                ),
+                self.row_offset,
+            ),
+            self.coerce_large_types,
+        );
+        let missing_columns = if self.fill_missing_columns {
+            self.lenient_columns
+        } else {
+            None
+        };
+        Ok(IpcBatches {
+            reader,
+            schema,
+            remaining: self.n_rows.unwrap_or(usize::MAX),
+            rows_read: 0,
+            row_count,
+            projection: self.projection,
+            schema_overwrite: self.schema_overwrite,
+            missing_columns,
+            flatten_structs: self.flatten_structs,
+            rename: self.rename,
+            output_order: self.output_order,
+            assume_sorted: self.assume_sorted,
+        })
+    }
+
+    fn finish_impl(mut self, check_eof: bool) -> Result<(DataFrame, ReadStats)> {
+        if self.tail.is_some()
+            && (self.n_rows.is_some() || self.row_offset > 0 || self.batch.is_some())
+        {
+            return Err(PolarsError::ComputeError(
+                "'tail' cannot be combined with 'n_rows', a row offset or a batch index".into(),
+            ));
+        }
+        check_rechunk_size(self.rechunk_size)?;
+        let rechunk = self.rechunk && self.rechunk_size.is_none();
+        let metadata = self.read_metadata()?;
+        self.check_metadata_version(&metadata)?;
+        self.check_limits(&metadata)?;
+        if self.verify {
+            self.verify_blocks(&metadata)?;
+        }
+        let (sorted_projection, schema) = self.resolve_projection(&metadata)?;
+
+        let include_row_count = self.row_count.is_some();
+        let mut row_count = self.absolute_row_count();
+        let mut row_offset = self.row_offset;
+        // the parallel read skips the blocks after `n_rows`, which are not seen by the reader
+        let mut blocks_skipped = false;
+        let (batches, n_batches): (Box<dyn ArrowReader + '_>, usize) = if let Some(n) = self.tail {
+            let (batches, start, offset) =
+                self.read_tail_batches(&metadata, n, sorted_projection.as_deref())?;
+            if let Some(rc) = &mut row_count {
+                rc.offset += start as IdxSize;
+            }
+            row_offset = offset;
+            let n_batches = batches.len();
+            (Box::new(DecodedBatches(batches.into_iter())), n_batches)
+        } else if let Some(batch) = self.batch {
+            let (chunk, rows_before) =
+                self.read_single_batch(&metadata, batch, sorted_projection.as_deref())?;
+            if let Some(rc) = &mut row_count {
+                rc.offset += rows_before as IdxSize;
+            }
+            (Box::new(DecodedBatches(vec![chunk].into_iter())), 1)
+        } else if self.parallel {
+            let batches = self.read_batches_parallel(&metadata, sorted_projection.as_deref())?;
+            let n_batches = batches.len();
+            blocks_skipped = n_batches < metadata.blocks.len();
+            (Box::new(DecodedBatches(batches.into_iter())), n_batches)
+        } else {
+            let reader =
+                read::FileReader::new(&mut self.reader, metadata.clone(), sorted_projection);
+            (Box::new(reader), metadata.blocks.len())
+        };
+        let batches = ProgressReader::new(batches, n_batches, self.progress.take());
+        let mut ipc_reader = CountingReader::new(CoerceReader::new(
+            OffsetReader::new(batches, row_offset),
+            self.coerce_large_types,
+        ));
+        let mut df = finish_reader(
+            &mut ipc_reader,
+            rechunk,
+            self.n_rows,
+            None,
+            None,
+            &schema,
+            row_count,
+            self.schema_overwrite.as_ref(),
+        )?;
+        // the sliced batch still holds the memory of the rows after `n_rows`, shrinking
+        // would merge the chunks that mirror the record batches if rechunk is off
+        if self.shrink && rechunk && df.height() < ipc_reader.rows {
+            df.shrink_to_fit();
+        }
+        if let Some(rechunk_size) = self.rechunk_size {
+            df = rechunk_to_size(df, rechunk_size)?;
+        }
+        let mut stats = ReadStats {
+            rows_read: df.height(),
+            batches_read: ipc_reader.batches,
+            reached_eof: ipc_reader.eof && !blocks_skipped,
+        };
+        // the `n_rows` limit was reached at the end of a batch, check if there is a next one
+        if check_eof && !stats.reached_eof && stats.rows_read == ipc_reader.rows {
+            stats.reached_eof = !blocks_skipped && ipc_reader.next_record_batch()?.is_none();
+        }
+        let mut df = fix_column_order(df, self.projection, include_row_count);
+        if let (Some(columns), true) = (&self.lenient_columns, self.fill_missing_columns) {
+            df = add_missing_columns(df, columns, include_row_count)?;
+        }
+        if self.flatten_structs {
+            df = flatten_structs(df)?;
+        }
+        if let Some(rename) = &self.rename {
+            apply_rename(&mut df, rename)?;
+        }
+        if let Some(columns) = &self.output_order {
+            df = df.select(columns)?;
+        }
+        if let Some(columns) = &self.assume_sorted {
+            set_sorted(&mut df, columns)?;
+        }
+        Ok((df, stats))
+    }
+
+    /// Shift the `row_count` by the skipped rows so it reflects the position in the file.
+    fn absolute_row_count(&mut self) -> Option<RowCount> {
+        let row_offset = self.row_offset as IdxSize;
+        self.row_count.take().map(|mut rc| {
+            rc.offset += row_offset;
+            rc
+        })
+    }
+
+    // todo! hoist to lazy crate
+    /// Read the file for a scan: filter the rows with `predicate` and apply `aggregate` and
+    /// `projection`. Options that change which batches are read or the shape of the output,
+    /// e.g. [`IpcReader::with_tail`] or [`IpcReader::with_rename`], are only applied by
+    /// [`IpcReader::finish`] and result in an error here.
+    #[cfg(feature = "lazy")]
+    pub fn finish_with_scan_ops(
+        mut self,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+        aggregate: Option<&[ScanAggregation]>,
+        projection: Option<Vec<usize>>,
+    ) -> Result<DataFrame> {
+        let unsupported = [
+            (self.tail.is_some(), "'tail'"),
+            (self.batch.is_some(), "a batch index"),
+            (self.parallel, "parallel reading"),
+            (self.rename.is_some(), "renaming columns"),
+            (self.output_order.is_some(), "an output column order"),
+            (self.assume_sorted.is_some(), "sorted columns"),
+            (self.coerce_large_types, "coercing large types"),
+            (self.flatten_structs, "flattening structs"),
+            (self.lenient_columns.is_some(), "lenient columns"),
+        ];
+        if let Some((_, option)) = unsupported.iter().find(|(set, _)| *set) {
+            return Err(PolarsError::InvalidOperation(
+                format!("{} cannot be combined with a scan of the IPC file", option).into(),
+            ));
+        }
+        check_rechunk_size(self.rechunk_size)?;
+        let rechunk = self.rechunk && self.rechunk_size.is_none();
+        let metadata = self.read_metadata()?;
+        self.check_metadata_version(&metadata)?;
+        self.check_limits(&metadata)?;
+        if self.verify {
+            self.verify_blocks(&metadata)?;
+        }
+        check_schema_overwrite(self.schema_overwrite.as_ref(), &metadata.schema)?;
+        if let Some(projection) = &projection {
+            check_projection(projection, &metadata.schema)?;
+        }
+
+        let sorted_projection = projection.clone().map(|mut proj| {
+            proj.sort_unstable();
+            proj
+        });
+
+        let schema = if let Some(projection) = &sorted_projection {
+            apply_projection(&metadata.schema, projection)
+        } else {
+            metadata.schema.clone()
+        };
+        to_polars_schema(&schema)?;
+
+        let include_row_count = self.row_count.is_some();
+        // skipping batches would shift the row count and the row offset
+        let stats_projection = match &predicate {
+            Some(predicate) if self.statistics && !include_row_count && self.row_offset == 0 => {
+                predicate_projection(predicate.as_ref(), &metadata.schema)
+            }
+            _ => None,
+        };
+        let row_count = self.absolute_row_count();
+        let batches: Box<dyn ArrowReader + '_> = match stats_projection {
+            Some(stats_projection) => Box::new(StatisticsReader::new(
+                &mut self.reader,
+                metadata,
+                sorted_projection,
+                stats_projection,
+                predicate.clone().unwrap(),
+            )?),
+            None => Box::new(read::FileReader::new(
+                &mut self.reader,
+                metadata,
+                sorted_projection,
+            )),
+        };
+        let reader = OffsetReader::new(batches, self.row_offset);
+
+        let mut df = finish_reader(
+            reader,
+            rechunk,
+            self.n_rows,
+            predicate,
+            aggregate,
+            &schema,
+            row_count,
+            self.schema_overwrite.as_ref(),
+        )?;
+        if let Some(rechunk_size) = self.rechunk_size {
+            df = rechunk_to_size(df, rechunk_size)?;
+        }
+        Ok(fix_column_order(df, projection, include_row_count))
+    }
+}
+
+/// Metadata of an IPC file, see [`IpcReader::metadata`].
+#[derive(Debug, Clone)]
+pub struct IpcFileMetadata {
+    /// Number of record batches in the file.
+    pub num_batches: usize,
+    /// Total number of rows in the file.
+    pub num_rows: usize,
+    /// Number of rows per record batch.
+    pub batch_lengths: Vec<usize>,
+    /// Compression of the record batches, `None` if the file is not compressed.
+    pub compression: Option<IpcCompression>,
+    /// Arrow schema of the file.
+    pub schema: ArrowSchema,
+}
+
+pub(super) const CONTINUATION_MARKER: [u8; 4] = [0xff; 4];
+
+/// Read the flatbuffer metadata of the message at `offset` into `scratch`.
+fn read_message_metadata<R: Read + Seek>(
+    reader: &mut R,
+    offset: u64,
+    scratch: &mut Vec<u8>,
+) -> Result<()> {
+    let file_len = reader.seek(SeekFrom::End(0))?;
+    reader.seek(SeekFrom::Start(offset))?;
+    let mut buf = [0u8; 4];
+    reader.read_exact(&mut buf)?;
+    // files written before arrow 0.15 don't have the continuation marker
+    if buf == CONTINUATION_MARKER {
+        reader.read_exact(&mut buf)?;
+    }
+    let meta_len = i32::from_le_bytes(buf);
+    let position = reader.stream_position()?;
+    // a corrupt length would otherwise allocate an arbitrarily large buffer
+    if meta_len < 0 || position + meta_len as u64 > file_len {
+        return Err(PolarsError::ComputeError(
+            format!(
+                "invalid IPC message at offset {}: metadata length {} exceeds the file of {} bytes",
+                offset, meta_len, file_len
+            )
+            .into(),
+        ));
+    }
+    let meta_len = meta_len as usize;
+    scratch.clear();
+    scratch.resize(meta_len, 0);
+    reader.read_exact(scratch)?;
+    Ok(())
+}
+
+/// Read the metadata version of the message at `offset`.
+fn read_metadata_version<R: Read + Seek>(
+    reader: &mut R,
+    offset: u64,
+    scratch: &mut Vec<u8>,
+) -> Result<arrow_format::ipc::MetadataVersion> {
+    use arrow_format::ipc::planus::ReadAsRoot;
+
+    read_message_metadata(reader, offset, scratch)?;
+    arrow_format::ipc::MessageRef::read_as_root(scratch)
+        .and_then(|message| message.version())
+        .map_err(|e| {
+            PolarsError::ComputeError(
+                format!(
+                    "could not read the metadata version of the IPC file: {:?}",
+                    e
+                )
+                .into(),
+            )
+        })
+}
+
+/// Read the header of the record batch message at `offset`, without reading its body.
+/// Returns the number of rows and the compression of the record batch.
+fn read_batch_header<R: Read + Seek>(
+    reader: &mut R,
+    offset: u64,
+    scratch: &mut Vec<u8>,
+) -> Result<(usize, Option<IpcCompression>)> {
+    use arrow_format::ipc::planus::ReadAsRoot;
+    use arrow_format::ipc::{CompressionType, MessageHeaderRef, MessageRef};
+
+    read_message_metadata(reader, offset, scratch)?;
+    let to_err = |e| {
+        PolarsError::ComputeError(format!("could not read IPC record batch header: {:?}", e).into())
+    };
+    let message = MessageRef::read_as_root(scratch).map_err(to_err)?;
+    match message.header().map_err(to_err)? {
+        Some(MessageHeaderRef::RecordBatch(batch)) => {
+            let length = batch.length().map_err(to_err)? as usize;
+            let compression = match batch.compression().map_err(to_err)? {
+                None => None,
+                Some(compression) => match compression.codec().map_err(to_err)? {
+                    CompressionType::Lz4Frame => Some(IpcCompression::LZ4),
+                    CompressionType::Zstd => Some(IpcCompression::ZSTD),
+                },
+            };
+            Ok((length, compression))
+        }
+        _ => Err(PolarsError::ComputeError(
+            format!("expected a record batch message at offset {}", offset).into(),
+        )),
+    }
+}
+
+/// Presents the bytes at the end of a file as a file of `file_len` bytes, so the footer can
+/// be read with [`read::read_file_metadata`]. Only the footer and the magic at the start of
+/// the file can be read.
+struct FooterReader<'a> {
+    footer: &'a [u8],
+    file_len: u64,
+    position: u64,
+}
+
+impl<'a> FooterReader<'a> {
+    fn new(footer: &'a [u8], file_len: u64) -> Result<Self> {
+        if (footer.len() as u64) > file_len {
+            return Err(PolarsError::ComputeError(
+                format!(
+                    "the IPC footer of {} bytes is longer than the file of {} bytes",
+                    footer.len(),
+                    file_len
+                )
+                .into(),
+            ));
+        }
+        Ok(Self {
+            footer,
+            file_len,
+            position: 0,
+        })
+    }
+}
+
+impl Read for FooterReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let footer_start = self.file_len - self.footer.len() as u64;
+        let bytes: &[u8] = if self.position >= footer_start {
+            &self.footer[(self.position - footer_start) as usize..]
+        } else if self.position < 6 {
+            &b"ARROW1"[self.position as usize..]
+        } else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "the IPC footer does not contain the complete footer of the file",
+            ));
+        };
+        let n = bytes.len().min(buf.len());
+        buf[..n].copy_from_slice(&bytes[..n]);
+        self.position += n as u64;
+        Ok(n)
+    }
+}
+
+impl Seek for FooterReader<'_> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        seek_in_file(&mut self.position, self.file_len, pos)
+    }
+}
+
+/// Presents the bytes of a block that is read into memory at their offset in the file, so
+/// the block can be decoded by arrow's readers without reading the rest of the file.
+struct BlockReader<'a> {
+    bytes: &'a [u8],
+    start: u64,
+    file_len: u64,
+    position: u64,
+}
+
+impl<'a> BlockReader<'a> {
+    fn new(bytes: &'a [u8], start: u64, file_len: u64) -> Self {
+        Self {
+            bytes,
+            start,
+            file_len,
+            position: start,
+        }
+    }
+}
+
+impl Read for BlockReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let end = self.start + self.bytes.len() as u64;
+        if self.position < self.start || self.position > end {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "the IPC record batch refers to bytes outside of its block",
+            ));
+        }
+        let bytes = &self.bytes[(self.position - self.start) as usize..];
+        let n = bytes.len().min(buf.len());
+        buf[..n].copy_from_slice(&bytes[..n]);
+        self.position += n as u64;
+        Ok(n)
+    }
+}
+
+impl Seek for BlockReader<'_> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        seek_in_file(&mut self.position, self.file_len, pos)
+    }
+}
+
+/// Apply `pos` to the `position` in a file of `file_len` bytes.
+fn seek_in_file(position: &mut u64, file_len: u64, pos: SeekFrom) -> std::io::Result<u64> {
+    let new_position = match pos {
+        SeekFrom::Start(offset) => Some(offset),
+        SeekFrom::End(offset) => (file_len as i64).checked_add(offset).map(|p| p as u64),
+        SeekFrom::Current(offset) => (*position as i64).checked_add(offset).map(|p| p as u64),
+    };
+    match new_position {
+        Some(new_position) if new_position as i64 >= 0 => {
+            *position = new_position;
+            Ok(new_position)
+        }
+        _ => Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "invalid seek to a negative position",
+        )),
+    }
+}
+
+impl<'a> IpcReader<Cursor<&'a [u8]>> {
+    /// Read an IPC file that is held in memory, e.g. a value from a cache or a message
+    /// queue. Options such as the projection can be set on the returned reader.
+    pub fn from_slice(bytes: &'a [u8]) -> Self {
+        Self::new(Cursor::new(bytes))
+    }
+}
+
+/// Read the IPC file in `bytes` into a `DataFrame`. Use [`IpcReader::from_slice`] to set
+/// options such as the projection.
+pub fn read_ipc_from_slice(bytes: &[u8]) -> Result<DataFrame> {
+    IpcReader::from_slice(bytes).finish()
+}
+
+impl<R> SerReader<R> for IpcReader<R>
+where
+    R: Read + Seek,
+{
+    fn new(reader: R) -> Self {
+        IpcReader {
+            reader,
+            rechunk: true,
+            n_rows: None,
+            columns: None,
+            projection: None,
+            row_count: None,
+            schema_overwrite: None,
+            row_offset: 0,
+            parallel: false,
+            statistics: false,
+            batch: None,
+            tail: None,
+            metadata: None,
+            footer: None,
+            rename: None,
+            assume_sorted: None,
+            output_order: None,
+            verify: false,
+            progress: None,
+            column_selector: None,
+            lenient_columns: None,
+            fill_missing_columns: false,
+            shrink: true,
+            coerce_large_types: false,
+            max_rows: None,
+            max_bytes: None,
+            flatten_structs: false,
+            rechunk_size: None,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(self) -> Result<DataFrame> {
+        self.finish_impl(false).map(|(df, _)| df)
+    }
+}
+
+/// Read Arrows IPC format into a DataFrame from an asynchronous source.
+///
+/// The source is buffered in memory and then decoded with [`IpcReader`].
+///
+/// # Example
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_io::ipc::IpcReaderAsync;
+///
+/// async fn example() -> Result<DataFrame> {
+///     let file = tokio::fs::File::open("file.ipc").await?;
+///
+///     IpcReaderAsync::new(file)
+///         .with_n_rows(Some(10))
+///         .finish_async()
+///         .await
+/// }
+/// ```
+#[cfg(feature = "async")]
+#[must_use]
+pub struct IpcReaderAsync<R> {
+    reader: R,
+    n_rows: Option<usize>,
+    projection: Option<Vec<usize>>,
+    columns: Option<Vec<String>>,
+}
+
+#[cfg(feature = "async")]
+impl<R> IpcReaderAsync<R>
+where
+    R: tokio::io::AsyncRead + tokio::io::AsyncSeek + Unpin,
+{
+    pub fn new(reader: R) -> Self {
+        IpcReaderAsync {
+            reader,
+            n_rows: None,
+            projection: None,
+            columns: None,
+        }
+    }
+
+    /// Stop reading when `n` rows are read.
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Columns to select/ project. Selecting the same column twice results in an error.
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Set the reader's column projection. This counts from 0, meaning that
+    /// `vec![0, 4]` would select the 1st and 5th column. Duplicate indices are not
+    /// deduplicated but result in an error.
+    pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Read the IPC file into a DataFrame.
+    pub async fn finish_async(mut self) -> Result<DataFrame> {
+        use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+        self.reader.seek(SeekFrom::Start(0)).await?;
+        let mut buf = vec![];
+        self.reader.read_to_end(&mut buf).await?;
+
+        IpcReader::new(Cursor::new(buf))
+            .with_n_rows(self.n_rows)
+            .with_columns(self.columns)
+            .with_projection(self.projection)
+            .finish()
+    }
+}
+
+/// The record batches of an IPC file as `DataFrame`s, see [`IpcReader::into_batches`].
+struct IpcBatches<R: Read + Seek> {
+    reader: CoerceReader<OffsetReader<ProgressReader<read::FileReader<R>>>>,
+    schema: ArrowSchema,
+    remaining: usize,
+    rows_read: usize,
+    row_count: Option<RowCount>,
+    projection: Option<Vec<usize>>,
+    schema_overwrite: Option<Schema>,
+    missing_columns: Option<Vec<String>>,
+    flatten_structs: bool,
+    rename: Option<Vec<(String, String)>>,
+    output_order: Option<Vec<String>>,
+    assume_sorted: Option<Vec<String>>,
+}
+
+impl<R: Read + Seek> IpcBatches<R> {
+    fn next_df(&mut self) -> Result<Option<DataFrame>> {
+        let batch = match self.reader.next_record_batch()? {
+            Some(batch) => batch,
+            None => return Ok(None),
+        };
+        let mut df = DataFrame::try_from((batch, self.schema.fields.as_slice()))?;
+        if df.height() > self.remaining {
+            df = df.slice(0, self.remaining);
+        }
+        self.remaining -= df.height();
+
+        if let Some(schema_overwrite) = &self.schema_overwrite {
+            apply_schema_overwrite(&mut df, schema_overwrite)?;
+        }
+        if let Some(rc) = &self.row_count {
+            df.with_row_count_mut(&rc.name, Some(rc.offset + self.rows_read as IdxSize));
+        }
+        self.rows_read += df.height();
+
+        let include_row_count = self.row_count.is_some();
+        let mut df = fix_column_order(df, self.projection.clone(), include_row_count);
+        if let Some(columns) = &self.missing_columns {
+            df = add_missing_columns(df, columns, include_row_count)?;
+        }
+        if self.flatten_structs {
+            df = flatten_structs(df)?;
+        }
+        if let Some(rename) = &self.rename {
+            apply_rename(&mut df, rename)?;
+        }
+        if let Some(columns) = &self.output_order {
+            df = df.select(columns)?;
+        }
+        if let Some(columns) = &self.assume_sorted {
+            set_sorted(&mut df, columns)?;
+        }
+        Ok(Some(df))
+    }
+}
+
+impl<R: Read + Seek> Iterator for IpcBatches<R> {
+    type Item = Result<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let out = self.next_df().transpose();
+        if matches!(out, Some(Err(_))) {
+            self.remaining = 0;
+        }
+        out
+    }
+}
+
+/// Convert the schema of the IPC file to a polars schema. Returns an error naming the
+/// column and its arrow datatype if the datatype is not supported.
+pub(super) fn to_polars_schema(arrow_schema: &ArrowSchema) -> Result<Schema> {
+    Schema::try_from_fallible(arrow_schema.fields.iter().map(|fld| {
+        let dtype = DataType::try_from_arrow(fld.data_type()).map_err(|err| {
+            PolarsError::ComputeError(
+                format!("cannot read column \"{}\": {}", fld.name, err).into(),
+            )
+        })?;
+        Ok(Field::new(&fld.name, dtype))
+    }))
+}
+
+/// Replace `LargeUtf8`, `LargeBinary` and `LargeList` by `Utf8`, `Binary` and `List`, also
+/// in nested types.
+pub(super) fn coerce_large_type(data_type: &ArrowDataType) -> ArrowDataType {
+    match data_type {
+        ArrowDataType::LargeUtf8 => ArrowDataType::Utf8,
+        ArrowDataType::LargeBinary => ArrowDataType::Binary,
+        ArrowDataType::List(fld) | ArrowDataType::LargeList(fld) => {
+            ArrowDataType::List(Box::new(coerce_large_field(fld)))
+        }
+        ArrowDataType::Struct(fields) => {
+            ArrowDataType::Struct(fields.iter().map(coerce_large_field).collect())
+        }
+        dt => dt.clone(),
+    }
+}
+
+fn coerce_large_field(fld: &ArrowField) -> ArrowField {
+    ArrowField {
+        data_type: coerce_large_type(&fld.data_type),
+        ..fld.clone()
+    }
+}
+
+/// Apply [`coerce_large_type`] to the fields of `schema`.
+fn coerce_large_schema(schema: &ArrowSchema) -> ArrowSchema {
+    ArrowSchema {
+        fields: schema.fields.iter().map(coerce_large_field).collect(),
+        metadata: schema.metadata.clone(),
+    }
+}
+
+/// Check that all the columns of the `schema_overwrite` exist in the file.
+fn check_schema_overwrite(
+    schema_overwrite: Option<&Schema>,
+    arrow_schema: &ArrowSchema,
+) -> Result<()> {
+    if let Some(schema_overwrite) = schema_overwrite {
+        for name in schema_overwrite.iter_names() {
+            if !arrow_schema.fields.iter().any(|fld| &fld.name == name) {
+                return Err(PolarsError::NotFound(format!(
+                    "Unable to overwrite the dtype of field named \"{}\", it does not exist in the IPC file",
+                    name
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Check that all the indices of the `projection` point to a column in the file
+/// and that no index is selected twice.
+pub(super) fn check_projection(projection: &[usize], arrow_schema: &ArrowSchema) -> Result<()> {
+    let n_columns = arrow_schema.fields.len();
+    let mut seen = PlHashSet::with_capacity(projection.len());
+    for &idx in projection {
+        if idx >= n_columns {
+            return Err(PolarsError::ComputeError(
+                format!(
+                    "projection index {} is out of bounds, the IPC file has {} columns",
+                    idx, n_columns
+                )
+                .into(),
+            ));
+        }
+        if !seen.insert(idx) {
+            return Err(PolarsError::ComputeError(
+                format!("duplicate projection index {}", idx).into(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Check that no column is selected twice.
+pub(super) fn check_columns(columns: &[String]) -> Result<()> {
+    let mut seen = PlHashSet::with_capacity(columns.len());
+    for name in columns {
+        if !seen.insert(name.as_str()) {
+            return Err(PolarsError::ComputeError(
+                format!("duplicate column name \"{}\"", name).into(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Rename the columns of `df` given as `(old, new)` pairs.
+fn apply_rename(df: &mut DataFrame, rename: &[(String, String)]) -> Result<()> {
+    let mut names = df.get_column_names_owned();
+    for (old, new) in rename {
+        let idx = df
+            .find_idx_by_name(old)
+            .ok_or_else(|| PolarsError::NotFound(old.clone()))?;
+        names[idx] = new.clone();
+    }
+    let mut seen = PlHashSet::with_capacity(names.len());
+    for name in &names {
+        if !seen.insert(name.as_str()) {
+            return Err(PolarsError::Duplicate(
+                format!(
+                    "column name \"{}\" occurs more than once after renaming",
+                    name
+                )
+                .into(),
+            ));
+        }
+    }
+    df.set_column_names(&names)
+}
+
+/// Select `columns` from `df` in order, where a column that is not in `df` is added as a
+/// column of null values. The row count column stays in front.
+fn add_missing_columns(df: DataFrame, columns: &[String], row_count: bool) -> Result<DataFrame> {
+    let mut out = Vec::with_capacity(columns.len() + 1);
+    if row_count {
+        out.push(df.get_columns()[0].clone());
+    }
+    for name in columns {
+        match df.column(name) {
+            Ok(s) => out.push(s.clone()),
+            Err(_) => {
+                let mut s = Series::full_null(name, df.height(), &DataType::Null);
+                s.rename(name);
+                out.push(s)
+            }
+        }
+    }
+    DataFrame::new(out)
+}
+
+/// Replace the struct columns of `df` with their fields, named `parent.child`, recursively.
+fn flatten_structs(df: DataFrame) -> Result<DataFrame> {
+    #[cfg(feature = "dtype-struct")]
+    {
+        if !df
+            .get_columns()
+            .iter()
+            .any(|s| matches!(s.dtype(), DataType::Struct(_)))
+        {
+            return Ok(df);
+        }
+        let mut columns = Vec::with_capacity(df.width());
+        for s in df.get_columns() {
+            push_flattened(&mut columns, s.clone());
+        }
+        let mut seen = PlHashSet::with_capacity(columns.len());
+        for s in &columns {
+            if !seen.insert(s.name()) {
+                return Err(PolarsError::Duplicate(
+                    format!(
+                        "column name \"{}\" occurs more than once after flattening the struct columns",
+                        s.name()
+                    )
+                    .into(),
+                ));
+            }
+        }
+        DataFrame::new(columns)
+    }
+    #[cfg(not(feature = "dtype-struct"))]
+    Ok(df)
+}
+
+#[cfg(feature = "dtype-struct")]
+fn push_flattened(columns: &mut Vec<Series>, s: Series) {
+    match s.struct_() {
+        Ok(ca) => {
+            for field in ca.fields() {
+                let mut field = field.clone();
+                field.rename(&format!("{}.{}", s.name(), field.name()));
+                push_flattened(columns, field);
+            }
+        }
+        Err(_) => columns.push(s),
+    }
+}
+
+/// Check that a rechunk size passed to [`IpcReader::with_rechunk_size`] is not zero.
+fn check_rechunk_size(rechunk_size: Option<usize>) -> Result<()> {
+    if rechunk_size == Some(0) {
+        return Err(PolarsError::ComputeError(
+            "the rechunk size must be greater than 0".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Split the columns of `df` into chunks of `rechunk_size` rows, the last chunk may be
+/// shorter. Only the rows of a chunk that span several record batches are copied.
+fn rechunk_to_size(df: DataFrame, rechunk_size: usize) -> Result<DataFrame> {
+    let mut pieces = split_by_batch_size(df, Some(rechunk_size)).into_iter();
+    // `split_by_batch_size` returns at least one piece
+    let mut out = pieces.next().unwrap();
+    out.rechunk();
+    for mut piece in pieces {
+        piece.rechunk();
+        out.vstack_mut(&piece)?;
+    }
+    Ok(out)
+}
+
+/// Flag the columns of `df` named in `columns` as sorted in ascending order.
+fn set_sorted(df: &mut DataFrame, columns: &[String]) -> Result<()> {
+    for name in columns {
+        let idx = df
+            .find_idx_by_name(name)
+            .ok_or_else(|| PolarsError::NotFound(name.clone()))?;
+        df.get_columns_mut()[idx].set_sorted(false);
+    }
+    Ok(())
+}
+
+fn fix_column_order(df: DataFrame, projection: Option<Vec<usize>>, row_count: bool) -> DataFrame {
+    if let Some(proj) = projection {
+        let offset = if row_count { 1 } else { 0 };
+        let mut args = (0..proj.len()).zip(proj).collect::<Vec<_>>();
+        // first el of tuple is argument index
+        // second el is the projection index
+        args.sort_unstable_by_key(|tpl| tpl.1);
+        let cols = df.get_columns();
+
+        let iter = args.iter().map(|tpl| cols[tpl.0 + offset].clone());
+        let cols = if row_count {
+            let mut new_cols = vec![df.get_columns()[0].clone()];
+            new_cols.extend(iter);
+            new_cols
+        } else {
+            iter.collect()
+        };
+
+        DataFrame::new_no_checks(cols)
+    } else {
+        df
+    }
+}
@@ -0,0 +1,300 @@
+use super::read::{check_columns, check_projection, to_polars_schema};
+use super::write::split_at_chunk_boundaries;
+use crate::prelude::*;
+use crate::{finish_reader, ArrowReader, ArrowResult, RowCount, WriterFactory};
+use arrow::io::ipc::write::WriteOptions;
+use arrow::io::ipc::{read, write};
+use polars_core::frame::ArrowChunk;
+use polars_core::prelude::*;
+use std::io::{Read, Write};
+use std::path::PathBuf;
+
+/// Read Arrows Stream IPC format into a DataFrame
+///
+/// Contrary to the file format, the stream format has no footer and does not
+/// need the reader to be seekable. This makes it suitable for reading from
+/// sockets, pipes or other non-seekable sources.
+///
+/// # Example
+/// ```
+/// use polars_core::prelude::*;
+/// use std::fs::File;
+/// use polars_io::ipc::IpcStreamReader;
+/// use polars_io::SerReader;
+///
+/// fn example() -> Result<DataFrame> {
+///     let file = File::open("file.ipc").expect("file not found");
+///
+///     IpcStreamReader::new(file)
+///         .finish()
+/// }
+/// ```
+#[must_use]
+pub struct IpcStreamReader<R> {
+    /// File or Stream object
+    reader: R,
+    /// Aggregates chunks afterwards to a single chunk.
+    rechunk: bool,
+    n_rows: Option<usize>,
+    projection: Option<Vec<usize>>,
+    columns: Option<Vec<String>>,
+    row_count: Option<RowCount>,
+    metadata: Option<read::StreamMetadata>,
+}
+
+impl<R: Read> IpcStreamReader<R> {
+    /// Get schema of the Ipc Stream
+    pub fn schema(&mut self) -> Result<Schema> {
+        to_polars_schema(&self.metadata()?.schema)
+    }
+
+    /// Get arrow schema of the Ipc Stream, this is faster than creating a polars schema.
+    pub fn arrow_schema(&mut self) -> Result<ArrowSchema> {
+        Ok(self.metadata()?.schema)
+    }
+
+    /// Stop reading when `n` rows are read.
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Columns to select/ project. Selecting the same column twice results in an error.
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Add a `row_count` column.
+    pub fn with_row_count(mut self, row_count: Option<RowCount>) -> Self {
+        self.row_count = row_count;
+        self
+    }
+
+    /// Set the reader's column projection. This counts from 0, meaning that
+    /// `vec![0, 4]` would select the 1st and 5th column. Duplicate indices are not
+    /// deduplicated but result in an error.
+    pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// The stream metadata is at the start of the stream and can only be read once,
+    /// so we cache it for subsequent calls.
+    fn metadata(&mut self) -> Result<read::StreamMetadata> {
+        match &self.metadata {
+            None => {
+                let metadata = read::read_stream_metadata(&mut self.reader)?;
+                self.metadata = Some(metadata.clone());
+                Ok(metadata)
+            }
+            Some(md) => Ok(md.clone()),
+        }
+    }
+}
+
+/// Wraps the arrow [`read::StreamReader`] and selects the projected columns
+/// from every record batch.
+struct ProjectedStreamReader<R: Read> {
+    reader: read::StreamReader<R>,
+    projection: Option<Vec<usize>>,
+}
+
+impl<R> ArrowReader for ProjectedStreamReader<R>
+where
+    R: Read,
+{
+    fn next_record_batch(&mut self) -> ArrowResult<Option<ArrowChunk>> {
+        match self.reader.next() {
+            None => Ok(None),
+            Some(Err(e)) => Err(e),
+            // the stream is not finished, but no new data is available;
+            // we stop reading as we don't want to block on the source
+            Some(Ok(read::StreamState::Waiting)) => Ok(None),
+            Some(Ok(read::StreamState::Some(chunk))) => match &self.projection {
+                None => Ok(Some(chunk)),
+                Some(projection) => {
+                    let arrays = chunk.into_arrays();
+                    let arrays = projection.iter().map(|i| arrays[*i].clone()).collect();
+                    Ok(Some(ArrowChunk::new(arrays)))
+                }
+            },
+        }
+    }
+}
+
+impl<R> SerReader<R> for IpcStreamReader<R>
+where
+    R: Read,
+{
+    fn new(reader: R) -> Self {
+        IpcStreamReader {
+            reader,
+            rechunk: true,
+            n_rows: None,
+            columns: None,
+            projection: None,
+            row_count: None,
+            metadata: None,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(mut self) -> Result<DataFrame> {
+        let rechunk = self.rechunk;
+        let metadata = self.metadata()?;
+
+        if let Some(columns) = self.columns.take() {
+            check_columns(&columns)?;
+            let prj = columns_to_projection(columns, &metadata.schema)?;
+            self.projection = Some(prj);
+        }
+        if let Some(projection) = &self.projection {
+            check_projection(projection, &metadata.schema)?;
+        }
+
+        // the batches are projected in the requested order, so there is no need
+        // to restore the column order afterwards.
+        let schema = if let Some(projection) = &self.projection {
+            apply_projection(&metadata.schema, projection)
+        } else {
+            metadata.schema.clone()
+        };
+
+        let ipc_reader = ProjectedStreamReader {
+            reader: read::StreamReader::new(&mut self.reader, metadata),
+            projection: self.projection,
+        };
+        finish_reader(
+            ipc_reader,
+            rechunk,
+            self.n_rows,
+            None,
+            None,
+            &schema,
+            self.row_count,
+            None,
+        )
+    }
+}
+
+/// Write a DataFrame to Arrow's Streaming IPC format
+///
+/// Contrary to [`IpcWriter`], no footer is written, and the batches are written as they
+/// are produced from the chunks of the `DataFrame`.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::ipc::IpcStreamWriter;
+/// use std::fs::File;
+/// use polars_io::SerWriter;
+///
+/// fn example(df: &mut DataFrame) -> Result<()> {
+///     let mut file = File::create("file.ipc").expect("could not create file");
+///
+///     IpcStreamWriter::new(&mut file)
+///         .finish(df)
+/// }
+///
+/// ```
+#[must_use]
+pub struct IpcStreamWriter<W> {
+    writer: W,
+    compression: Option<write::Compression>,
+}
+
+impl<W> IpcStreamWriter<W> {
+    /// Set the compression used. Defaults to None.
+    ///
+    /// The buffers of every record batch in the stream are compressed. [`IpcStreamReader`]
+    /// decompresses them transparently, but readers that predate buffer compression in the
+    /// Arrow IPC format (added in Arrow 4.0) reject compressed streams.
+    pub fn with_compression(mut self, compression: Option<write::Compression>) -> Self {
+        self.compression = compression;
+        self
+    }
+}
+
+impl<W> SerWriter<W> for IpcStreamWriter<W>
+where
+    W: Write,
+{
+    fn new(writer: W) -> Self {
+        IpcStreamWriter {
+            writer,
+            compression: None,
+        }
+    }
+
+    fn finish(&mut self, df: &mut DataFrame) -> Result<()> {
+        let mut ipc_stream_writer = write::StreamWriter::new(
+            &mut self.writer,
+            WriteOptions {
+                compression: self.compression,
+            },
+        );
+
+        ipc_stream_writer.start(&df.schema().to_arrow(), None)?;
+
+        // the chunks are written one by one, chunks that are not aligned between the
+        // columns are sliced at the boundaries instead of rechunking the whole frame.
+        if df.width() > 0 {
+            for part in split_at_chunk_boundaries(df) {
+                for batch in part.iter_chunks() {
+                    ipc_stream_writer.write(&batch, None)?
+                }
+            }
+        }
+        let _ = ipc_stream_writer.finish()?;
+        Ok(())
+    }
+}
+
+/// Creates [`IpcStreamWriter`]s, the streaming counterpart of [`IpcWriterOption`].
+pub struct IpcStreamWriterOption {
+    compression: Option<write::Compression>,
+    extension: PathBuf,
+}
+
+impl IpcStreamWriterOption {
+    pub fn new() -> Self {
+        Self {
+            compression: None,
+            extension: PathBuf::from(".arrows"),
+        }
+    }
+
+    /// Set the compression used. Defaults to None.
+    pub fn with_compression(mut self, compression: Option<write::Compression>) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Set the extension. Defaults to ".arrows".
+    pub fn with_extension(mut self, extension: PathBuf) -> Self {
+        self.extension = extension;
+        self
+    }
+}
+
+impl Default for IpcStreamWriterOption {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WriterFactory for IpcStreamWriterOption {
+    fn create_writer<W: Write + 'static>(&self, writer: W) -> Box<dyn SerWriter<W>> {
+        Box::new(IpcStreamWriter::new(writer).with_compression(self.compression))
+    }
+
+    fn extension(&self) -> PathBuf {
+        self.extension.to_owned()
+    }
+}
@@ -0,0 +1,660 @@
+use super::read::CONTINUATION_MARKER;
+use crate::prelude::*;
+use crate::utils::resolve_homedir;
+use crate::WriterFactory;
+use arrow::array::Array;
+use arrow::io::ipc::write::WriteOptions;
+use arrow::io::ipc::{write, IpcField};
+use polars_core::frame::ArrowChunk;
+use polars_core::prelude::*;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+pub use write::Compression as IpcCompression;
+
+/// Write `df` as an IPC file to a new buffer, compressed with `compression`. The buffer can
+/// be read back with [`read_ipc_from_slice`].
+pub fn write_ipc_to_vec(
+    df: &mut DataFrame,
+    compression: Option<IpcCompression>,
+) -> Result<Vec<u8>> {
+    let mut buf = vec![];
+    IpcWriter::new(&mut buf)
+        .with_compression(compression)
+        .finish(df)?;
+    Ok(buf)
+}
+
+/// Write `df` as an IPC file to `path`, compressed with `compression`. Missing parent
+/// directories are created and the extension `ipc` is added if `path` has no extension.
+/// Returns the path of the written file.
+pub fn write_ipc_to_path<P: AsRef<Path>>(
+    df: &mut DataFrame,
+    path: P,
+    compression: Option<IpcCompression>,
+) -> Result<PathBuf> {
+    let mut path = resolve_homedir(path.as_ref());
+    if path.extension().is_none() {
+        path.set_extension("ipc");
+    }
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let file = std::fs::File::create(&path)?;
+    IpcWriter::new(file)
+        .with_compression(compression)
+        .finish(df)?;
+    Ok(path)
+}
+
+/// Write a DataFrame to Arrow's IPC format
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::ipc::IpcWriter;
+/// use std::fs::File;
+/// use polars_io::SerWriter;
+///
+/// fn example(df: &mut DataFrame) -> Result<()> {
+///     let mut file = File::create("file.ipc").expect("could not create file");
+///
+///     IpcWriter::new(&mut file)
+///         .finish(df)
+/// }
+///
+/// ```
+#[must_use]
+pub struct IpcWriter<W> {
+    writer: W,
+    compression: Option<write::Compression>,
+    write_options: Option<WriteOptions>,
+    metadata: BTreeMap<String, String>,
+    dictionaries: IpcDictionaryMode,
+    batch_size: Option<usize>,
+    preserve_chunks: bool,
+}
+
+/// How `Categorical` columns are written to an IPC file.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IpcDictionaryMode {
+    /// Write `Categorical` columns as dictionary encoded arrays.
+    Keep,
+    /// Write the values of `Categorical` columns as plain strings.
+    Expand,
+}
+
+impl Default for IpcDictionaryMode {
+    fn default() -> Self {
+        IpcDictionaryMode::Keep
+    }
+}
+
+/// Cast the `Categorical` columns to `Utf8` if the dictionaries should be expanded.
+fn apply_dictionary_mode(df: &DataFrame, mode: IpcDictionaryMode) -> Result<Cow<DataFrame>> {
+    match mode {
+        IpcDictionaryMode::Keep => Ok(Cow::Borrowed(df)),
+        IpcDictionaryMode::Expand => {
+            let columns = df
+                .get_columns()
+                .iter()
+                .map(|s| match s.dtype() {
+                    #[cfg(feature = "dtype-categorical")]
+                    DataType::Categorical(_) => s.cast(&DataType::Utf8),
+                    _ => Ok(s.clone()),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Cow::Owned(DataFrame::new_no_checks(columns)))
+        }
+    }
+}
+
+/// Split `df` at the chunk boundaries of all its columns, so that every part has a single
+/// chunk per column and can be written as one record batch. The columns are sliced, no data
+/// is copied. A `df` without rows is a single part, whatever its chunks.
+pub(super) fn split_at_chunk_boundaries(df: &DataFrame) -> Vec<DataFrame> {
+    if df.height() == 0 {
+        return vec![df.agg_chunks()];
+    }
+    if !df.should_rechunk() {
+        return vec![df.clone()];
+    }
+    let mut boundaries = BTreeSet::new();
+    for s in df.get_columns() {
+        let mut offset = 0;
+        for length in s.chunk_lengths() {
+            offset += length;
+            boundaries.insert(offset);
+        }
+    }
+    let mut start = 0;
+    boundaries
+        .into_iter()
+        .filter(|&end| end > 0)
+        .map(|end| {
+            let part = df.slice(start as i64, end - start);
+            start = end;
+            part
+        })
+        .collect()
+}
+
+/// Slice `df` into parts of at most `batch_size` rows. The columns are sliced, no data
+/// is copied.
+pub(super) fn split_by_batch_size(df: DataFrame, batch_size: Option<usize>) -> Vec<DataFrame> {
+    match batch_size {
+        Some(batch_size) if df.height() > batch_size => (0..df.height())
+            .step_by(batch_size)
+            .map(|offset| df.slice(offset as i64, batch_size))
+            .collect(),
+        _ => vec![df],
+    }
+}
+
+/// Replace `Categorical` by `Utf8` if the dictionaries should be expanded.
+fn apply_dictionary_mode_schema(schema: &Schema, mode: IpcDictionaryMode) -> Schema {
+    match mode {
+        IpcDictionaryMode::Keep => schema.clone(),
+        IpcDictionaryMode::Expand => schema
+            .iter_fields()
+            .map(|fld| match fld.data_type() {
+                #[cfg(feature = "dtype-categorical")]
+                DataType::Categorical(_) => Field::new(fld.name(), DataType::Utf8),
+                _ => fld,
+            })
+            .collect(),
+    }
+}
+
+impl<W> IpcWriter<W> {
+    /// Set the compression used. Defaults to None.
+    pub fn with_compression(mut self, compression: Option<write::Compression>) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Set the options that are passed to arrow's IPC writer. The options replace the
+    /// compression set with [`IpcWriter::with_compression`], so if both are set, `options`
+    /// are used.
+    pub fn with_write_options(mut self, options: Option<WriteOptions>) -> Self {
+        self.write_options = options;
+        self
+    }
+
+    fn write_options(&self) -> WriteOptions {
+        self.write_options.unwrap_or(WriteOptions {
+            compression: self.compression,
+        })
+    }
+
+    /// Set whether `Categorical` columns are written as dictionary encoded arrays or as
+    /// plain strings. Defaults to [`IpcDictionaryMode::Keep`].
+    ///
+    /// Keeping the dictionaries saves space for columns with a low cardinality, expanding
+    /// them is more compatible with readers that don't support dictionary arrays.
+    pub fn with_dictionaries_as(mut self, mode: IpcDictionaryMode) -> Self {
+        self.dictionaries = mode;
+        self
+    }
+
+    /// Write record batches of at most `batch_size` rows. The chunks of the `DataFrame` are
+    /// sliced, so no data is copied. If `None`, every chunk is written as one record batch.
+    ///
+    /// A record batch never spans two chunks, so with [`IpcWriter::with_preserve_chunks`]
+    /// the chunk boundaries are kept and only large chunks are split. Disable preserving the
+    /// chunks to get batches of exactly `batch_size` rows, except for the last one.
+    pub fn with_batch_size(mut self, batch_size: Option<usize>) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Write every chunk of the `DataFrame` as its own record batch, so the chunk boundaries
+    /// are kept when the file is read. Chunks that are not aligned between the columns are
+    /// split at the boundaries of all columns. If `false`, the `DataFrame` is rechunked to
+    /// contiguous memory first and written as a single record batch. Defaults to `true`.
+    pub fn with_preserve_chunks(mut self, preserve_chunks: bool) -> Self {
+        self.preserve_chunks = preserve_chunks;
+        self
+    }
+
+    /// Set custom key-value metadata that is stored in the schema of the IPC file.
+    /// It can be read back with [`IpcReader::custom_metadata`].
+    pub fn with_metadata(mut self, metadata: BTreeMap<String, String>) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// Convert `schema` to an arrow schema and merge the custom metadata into it.
+    fn arrow_schema(&self, schema: &Schema) -> ArrowSchema {
+        let mut arrow_schema = schema.to_arrow();
+        arrow_schema.metadata.extend(self.metadata.clone());
+        arrow_schema
+    }
+}
+
+impl<W: Write> IpcWriter<W> {
+    /// Initialize the file with the given `schema` and return a writer to which
+    /// `DataFrame`s can be written incrementally with [`BatchedWriter::write_batch`].
+    /// Call [`BatchedWriter::finish`] to write the footer once all batches are written.
+    ///
+    /// An IPC file can only be read once it has a footer, see
+    /// [`BatchedWriter::finalize_partial`] to make the batches that are written so far
+    /// readable before the file is finished.
+    pub fn batched(self, schema: &Schema) -> Result<BatchedWriter<W>> {
+        let arrow_schema =
+            self.arrow_schema(&apply_dictionary_mode_schema(schema, self.dictionaries));
+        let options = self.write_options();
+        let encoder = FileEncoder::try_new(self.writer, &arrow_schema, options)?;
+
+        Ok(BatchedWriter {
+            encoder,
+            schema: schema.clone(),
+            dictionaries: self.dictionaries,
+        })
+    }
+}
+
+impl<W> SerWriter<W> for IpcWriter<W>
+where
+    W: Write,
+{
+    fn new(writer: W) -> Self {
+        IpcWriter {
+            writer,
+            compression: None,
+            write_options: None,
+            metadata: BTreeMap::new(),
+            dictionaries: IpcDictionaryMode::default(),
+            batch_size: None,
+            preserve_chunks: true,
+        }
+    }
+
+    fn finish(&mut self, df: &mut DataFrame) -> Result<()> {
+        if self.batch_size == Some(0) {
+            return Err(PolarsError::InvalidOperation(
+                "the batch size must be greater than 0".into(),
+            ));
+        }
+        let mut df = apply_dictionary_mode(df, self.dictionaries)?;
+        if !self.preserve_chunks {
+            df.to_mut().as_single_chunk_par();
+        }
+        let arrow_schema = self.arrow_schema(&df.schema());
+        let mut ipc_writer = write::FileWriter::try_new(
+            &mut self.writer,
+            &arrow_schema,
+            None,
+            self.write_options(),
+        )?;
+
+        // a frame without columns has nothing to put in a record batch, only the
+        // schema is written.
+        if df.width() == 0 {
+            let _ = ipc_writer.finish()?;
+            return Ok(());
+        }
+        // the chunks are written as separate record batches, chunks that are not
+        // aligned between the columns are split further. A frame without rows is
+        // written as a single empty record batch.
+        for part in split_at_chunk_boundaries(&df) {
+            for part in split_by_batch_size(part, self.batch_size) {
+                for batch in part.iter_chunks() {
+                    ipc_writer.write(&batch, None)?
+                }
+            }
+        }
+        let _ = ipc_writer.finish()?;
+        Ok(())
+    }
+}
+
+/// Writes `DataFrame`s as record batches to an IPC file, see [`IpcWriter::batched`].
+pub struct BatchedWriter<W: Write> {
+    encoder: FileEncoder<W>,
+    schema: Schema,
+    dictionaries: IpcDictionaryMode,
+}
+
+impl<W: Write> BatchedWriter<W> {
+    /// Write a batch to the IPC file.
+    ///
+    /// # Panics
+    /// The caller must ensure the chunks in the given `DataFrame` are aligned.
+    pub fn write_batch(&mut self, df: &DataFrame) -> Result<()> {
+        if df.schema() != self.schema {
+            return Err(PolarsError::SchemaMisMatch(
+                format!(
+                    "cannot write batch to IPC file; expected {:?}, got {:?}",
+                    self.schema,
+                    df.schema()
+                )
+                .into(),
+            ));
+        }
+        let df = apply_dictionary_mode(df, self.dictionaries)?;
+        let iter = df.iter_chunks();
+
+        for batch in iter {
+            self.encoder.write_chunk(&batch)?
+        }
+        Ok(())
+    }
+
+    /// Flush the bytes that are written so far to the underlying writer.
+    pub fn flush(&mut self) -> Result<()> {
+        self.encoder.writer.flush()?;
+        Ok(())
+    }
+
+    /// Write a footer for all batches that are written so far and flush, so that the file
+    /// can be read up to this point. Returns the length of the file after the footer.
+    ///
+    /// Batches that are written afterwards are appended after this footer and are only
+    /// readable after the next call to `finalize_partial` or [`BatchedWriter::finish`]. If
+    /// the producer stops while writing such a batch, the file is readable again once it
+    /// is truncated to the returned length.
+    pub fn finalize_partial(&mut self) -> Result<u64> {
+        self.encoder.write_footer()?;
+        self.encoder.writer.flush()?;
+        Ok(self.encoder.position)
+    }
+
+    /// Writes the footer of the IPC file.
+    pub fn finish(&mut self) -> Result<()> {
+        self.encoder.write_footer()?;
+        self.encoder.writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Writes the messages of an IPC file and records the block of every message it writes,
+/// so that a footer can be written in between two messages. The arrays are serialized
+/// with arrow2, the messages around them are built here.
+struct FileEncoder<W> {
+    writer: W,
+    position: u64,
+    options: WriteOptions,
+    ipc_fields: Vec<IpcField>,
+    schema: arrow_format::ipc::Schema,
+    // the file format cannot replace a dictionary, every id is written once
+    written_dictionaries: PlHashMap<i64, ArrayRef>,
+    dictionaries: Vec<arrow_format::ipc::Block>,
+    record_batches: Vec<arrow_format::ipc::Block>,
+}
+
+impl<W: Write> FileEncoder<W> {
+    /// Write the magic bytes and the schema message to `writer`.
+    fn try_new(mut writer: W, schema: &ArrowSchema, options: WriteOptions) -> Result<Self> {
+        use arrow_format::ipc::planus::ReadAsRoot;
+        use arrow_format::ipc::{MessageHeaderRef, MessageRef};
+
+        let ipc_fields = write::default_ipc_fields(&schema.fields);
+        let message = write::schema_to_bytes(schema, &ipc_fields);
+        // the footer repeats the schema of the schema message
+        let to_err =
+            |e| PolarsError::ComputeError(format!("could not read the IPC schema: {:?}", e).into());
+        let ipc_schema = match MessageRef::read_as_root(&message)
+            .map_err(to_err)?
+            .header()
+            .map_err(to_err)?
+        {
+            Some(MessageHeaderRef::Schema(schema)) => schema.try_into().map_err(to_err)?,
+            _ => unreachable!(),
+        };
+
+        // "ARROW1" padded to 8 bytes
+        writer.write_all(b"ARROW1\0\0")?;
+        let mut encoder = Self {
+            writer,
+            position: 8,
+            options,
+            ipc_fields,
+            schema: ipc_schema,
+            written_dictionaries: PlHashMap::new(),
+            dictionaries: vec![],
+            record_batches: vec![],
+        };
+        encoder.write_message(&message, &[])?;
+        Ok(encoder)
+    }
+
+    /// Write the dictionaries of `chunk` that are not written yet, followed by `chunk`
+    /// as a record batch.
+    fn write_chunk(&mut self, chunk: &ArrowChunk) -> Result<()> {
+        let mut dictionaries = vec![];
+        for (array, field) in chunk.arrays().iter().zip(&self.ipc_fields) {
+            collect_dictionaries(array.as_ref(), field, &mut dictionaries)?;
+        }
+        for (id, values) in dictionaries {
+            match self.written_dictionaries.get(&id) {
+                Some(written) if written.as_ref() == values.as_ref() => continue,
+                Some(_) => {
+                    return Err(PolarsError::InvalidOperation(
+                        "cannot replace a dictionary in an IPC file, \
+                        write the categoricals with the same rev-map or expand them"
+                            .into(),
+                    ))
+                }
+                None => {}
+            }
+            let (batch, body) = self.encode_batch(&[values.clone()], values.len());
+            let header = arrow_format::ipc::DictionaryBatch {
+                id,
+                data: Some(Box::new(batch)),
+                is_delta: false,
+            };
+            let block = self.write_encoded(
+                arrow_format::ipc::MessageHeader::DictionaryBatch(Box::new(header)),
+                &body,
+            )?;
+            self.dictionaries.push(block);
+            self.written_dictionaries.insert(id, values);
+        }
+
+        let (batch, body) = self.encode_batch(chunk.arrays(), chunk.len());
+        let block = self.write_encoded(
+            arrow_format::ipc::MessageHeader::RecordBatch(Box::new(batch)),
+            &body,
+        )?;
+        self.record_batches.push(block);
+        Ok(())
+    }
+
+    /// Serialize `arrays` of `length` rows into the header and the body of a record batch.
+    fn encode_batch(
+        &self,
+        arrays: &[ArrayRef],
+        length: usize,
+    ) -> (arrow_format::ipc::RecordBatch, Vec<u8>) {
+        use arrow_format::ipc::{BodyCompression, BodyCompressionMethod, CompressionType};
+
+        let mut nodes = vec![];
+        let mut buffers = vec![];
+        let mut body = vec![];
+        let mut offset = 0;
+        for array in arrays {
+            write::write(
+                array.as_ref(),
+                &mut buffers,
+                &mut body,
+                &mut nodes,
+                &mut offset,
+                cfg!(target_endian = "little"),
+                self.options.compression,
+            );
+        }
+        let compression = self.options.compression.map(|compression| {
+            let codec = match compression {
+                write::Compression::LZ4 => CompressionType::Lz4Frame,
+                write::Compression::ZSTD => CompressionType::Zstd,
+            };
+            Box::new(BodyCompression {
+                codec,
+                method: BodyCompressionMethod::Buffer,
+            })
+        });
+        let batch = arrow_format::ipc::RecordBatch {
+            length: length as i64,
+            nodes: Some(nodes),
+            buffers: Some(buffers),
+            compression,
+        };
+        (batch, body)
+    }
+
+    /// Write a message with `header` and `body` and return its block.
+    fn write_encoded(
+        &mut self,
+        header: arrow_format::ipc::MessageHeader,
+        body: &[u8],
+    ) -> Result<arrow_format::ipc::Block> {
+        let message = arrow_format::ipc::Message {
+            version: arrow_format::ipc::MetadataVersion::V5,
+            header: Some(header),
+            body_length: body.len() as i64,
+            custom_metadata: None,
+        };
+        let mut builder = arrow_format::ipc::planus::Builder::new();
+        let metadata = builder.finish(&message, None);
+        self.write_message(metadata, body)
+    }
+
+    /// Write the flatbuffer `metadata` of a message followed by its `body`, both padded
+    /// to 8 bytes, and return the block of the message.
+    fn write_message(&mut self, metadata: &[u8], body: &[u8]) -> Result<arrow_format::ipc::Block> {
+        // the continuation marker and the length precede the metadata
+        let meta_data_length = (8 + metadata.len() + 7) & !7;
+        let body_length = (body.len() + 7) & !7;
+
+        self.writer.write_all(&CONTINUATION_MARKER)?;
+        self.writer
+            .write_all(&((meta_data_length - 8) as i32).to_le_bytes())?;
+        self.writer.write_all(metadata)?;
+        self.writer
+            .write_all(&vec![0; meta_data_length - 8 - metadata.len()])?;
+        self.writer.write_all(body)?;
+        self.writer.write_all(&vec![0; body_length - body.len()])?;
+
+        let block = arrow_format::ipc::Block {
+            offset: self.position as i64,
+            meta_data_length: meta_data_length as i32,
+            body_length: body_length as i64,
+        };
+        self.position += (meta_data_length + body_length) as u64;
+        Ok(block)
+    }
+
+    /// Write the end of stream marker and a footer for the messages written so far. The
+    /// footer is not recorded as a block, the next message is written after it.
+    fn write_footer(&mut self) -> Result<()> {
+        let footer = arrow_format::ipc::Footer {
+            version: arrow_format::ipc::MetadataVersion::V5,
+            schema: Some(Box::new(self.schema.clone())),
+            dictionaries: Some(self.dictionaries.clone()),
+            record_batches: Some(self.record_batches.clone()),
+            custom_metadata: None,
+        };
+        let mut builder = arrow_format::ipc::planus::Builder::new();
+        let footer = builder.finish(&footer, None);
+
+        self.writer.write_all(&CONTINUATION_MARKER)?;
+        self.writer.write_all(&0i32.to_le_bytes())?;
+        self.writer.write_all(footer)?;
+        self.writer
+            .write_all(&(footer.len() as i32).to_le_bytes())?;
+        self.writer.write_all(b"ARROW1")?;
+        self.position += (8 + footer.len() + 4 + 6) as u64;
+        Ok(())
+    }
+}
+
+/// Collect the dictionaries in `array` with the ids that are assigned to them in `field`.
+fn collect_dictionaries(
+    array: &dyn Array,
+    field: &IpcField,
+    dictionaries: &mut Vec<(i64, ArrayRef)>,
+) -> Result<()> {
+    use arrow::array::{DictionaryArray, ListArray, StructArray};
+    use arrow::datatypes::IntegerType;
+
+    match array.data_type().to_logical_type() {
+        ArrowDataType::Dictionary(IntegerType::UInt32, _, _) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<DictionaryArray<u32>>()
+                .unwrap();
+            dictionaries.push((field.dictionary_id.unwrap(), array.values().clone()));
+        }
+        ArrowDataType::Dictionary(key_type, _, _) => {
+            return Err(PolarsError::InvalidOperation(
+                format!(
+                    "cannot write dictionaries with {:?} keys to an IPC file",
+                    key_type
+                )
+                .into(),
+            ))
+        }
+        ArrowDataType::List(_) => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            collect_dictionaries(array.values().as_ref(), &field.fields[0], dictionaries)?
+        }
+        ArrowDataType::LargeList(_) => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            collect_dictionaries(array.values().as_ref(), &field.fields[0], dictionaries)?
+        }
+        ArrowDataType::Struct(_) => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            for (values, field) in array.values().iter().zip(&field.fields) {
+                collect_dictionaries(values.as_ref(), field, dictionaries)?
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+pub struct IpcWriterOption {
+    compression: Option<write::Compression>,
+    extension: PathBuf,
+}
+
+impl IpcWriterOption {
+    pub fn new() -> Self {
+        Self {
+            compression: None,
+            extension: PathBuf::from(".ipc"),
+        }
+    }
+
+    /// Set the compression used. Defaults to None.
+    pub fn with_compression(mut self, compression: Option<write::Compression>) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Set the extension. Defaults to ".ipc".
+    pub fn with_extension(mut self, extension: PathBuf) -> Self {
+        self.extension = extension;
+        self
+    }
+}
+
+impl Default for IpcWriterOption {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WriterFactory for IpcWriterOption {
+    fn create_writer<W: Write + 'static>(&self, writer: W) -> Box<dyn SerWriter<W>> {
+        Box::new(IpcWriter::new(writer).with_compression(self.compression))
+    }
+
+    fn extension(&self) -> PathBuf {
+        self.extension.to_owned()
+    }
+}
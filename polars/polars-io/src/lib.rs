@@ -49,17 +49,19 @@ pub use options::*;
 use crate::aggregations::{apply_aggregations, ScanAggregation};
 #[cfg(any(feature = "ipc", feature = "json", feature = "avro"))]
 use crate::predicates::PhysicalIoExpr;
+#[cfg(any(feature = "ipc", feature = "json", feature = "avro"))]
+use crate::utils::apply_schema_overwrite;
 #[allow(unused)] // remove when updating to rust nightly >= 1.61
 use arrow::array::new_empty_array;
 use arrow::error::Result as ArrowResult;
 use polars_core::frame::ArrowChunk;
 use polars_core::prelude::*;
-use std::io::{Read, Seek, Write};
+use std::io::{Read, Write};
 use std::path::PathBuf;
 
 pub trait SerReader<R>
 where
-    R: Read + Seek,
+    R: Read,
 {
     fn new(reader: R) -> Self;
 
@@ -95,6 +97,18 @@ pub trait ArrowReader {
     fn next_record_batch(&mut self) -> ArrowResult<Option<ArrowChunk>>;
 }
 
+impl<R: ArrowReader + ?Sized> ArrowReader for Box<R> {
+    fn next_record_batch(&mut self) -> ArrowResult<Option<ArrowChunk>> {
+        (**self).next_record_batch()
+    }
+}
+
+impl<R: ArrowReader + ?Sized> ArrowReader for &mut R {
+    fn next_record_batch(&mut self) -> ArrowResult<Option<ArrowChunk>> {
+        (**self).next_record_batch()
+    }
+}
+
 #[cfg(any(feature = "ipc", feature = "json", feature = "avro"))]
 pub(crate) fn finish_reader<R: ArrowReader>(
     mut reader: R,
@@ -104,6 +118,7 @@ pub(crate) fn finish_reader<R: ArrowReader>(
     aggregate: Option<&[ScanAggregation]>,
     arrow_schema: &ArrowSchema,
     row_count: Option<RowCount>,
+    schema_overwrite: Option<&Schema>,
 ) -> Result<DataFrame> {
     use polars_core::utils::accumulate_dataframes_vertical;
 
@@ -115,6 +130,10 @@ pub(crate) fn finish_reader<R: ArrowReader>(
         num_rows += batch.len();
         let mut df = DataFrame::try_from((batch, arrow_schema.fields.as_slice()))?;
 
+        if let Some(schema_overwrite) = schema_overwrite {
+            apply_schema_overwrite(&mut df, schema_overwrite)?;
+        }
+
         if let Some(rc) = &row_count {
             df.with_row_count_mut(&rc.name, Some(current_num_rows + rc.offset));
         }
@@ -153,7 +172,11 @@ pub(crate) fn finish_reader<R: ArrowReader>(
                     Series::try_from((fld.name.as_str(), new_empty_array(fld.data_type.clone())))
                 })
                 .collect::<Result<_>>()?;
-            DataFrame::new(empty_cols)?
+            let mut df = DataFrame::new(empty_cols)?;
+            if let Some(schema_overwrite) = schema_overwrite {
+                apply_schema_overwrite(&mut df, schema_overwrite)?;
+            }
+            df
         } else {
             // If there are any rows, accumulate them into a df
             let mut df = accumulate_dataframes_vertical(parsed_dfs)?;
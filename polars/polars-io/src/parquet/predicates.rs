@@ -1,3 +1,4 @@
+pub use crate::predicates::{BatchStats, ColumnStats};
 use crate::ArrowResult;
 use arrow::array::Array;
 use arrow::compute::concatenate::concatenate;
@@ -5,64 +6,34 @@ use arrow::io::parquet::read::statistics::{self, deserialize, Statistics};
 use arrow::io::parquet::read::RowGroupMetaData;
 use polars_core::prelude::*;
 
-/// The statistics for a column in a Parquet file
-/// they typically hold
-/// - max value
-/// - min value
-/// - null_count
-#[cfg_attr(debug_assertions, derive(Debug))]
-pub struct ColumnStats(Statistics, Field);
-
-impl ColumnStats {
-    pub fn dtype(&self) -> DataType {
-        self.1.data_type().clone()
-    }
-
-    pub fn null_count(&self) -> Option<usize> {
-        match &self.0.null_count {
-            statistics::Count::Single(arr) => {
-                if arr.is_valid(0) {
-                    Some(arr.value(0) as usize)
-                } else {
-                    None
-                }
-            }
-            _ => None,
-        }
-    }
-
-    pub fn to_min_max(&self) -> Option<Series> {
-        let max_val = &*self.0.max_value;
-        let min_val = &*self.0.min_value;
-
-        let dtype = DataType::from(min_val.data_type());
-        if dtype.is_numeric() || matches!(dtype, DataType::Utf8) {
-            let arr = concatenate(&[min_val, max_val]).unwrap();
-            let s = Series::try_from(("", arr)).unwrap();
-            if s.null_count() > 0 {
-                None
+fn null_count(st: &Statistics) -> Option<usize> {
+    match &st.null_count {
+        statistics::Count::Single(arr) => {
+            if arr.is_valid(0) {
+                Some(arr.value(0) as usize)
             } else {
-                Some(s)
+                None
             }
-        } else {
-            None
         }
+        _ => None,
     }
 }
 
-/// A collection of column stats with a known schema.
-pub struct BatchStats {
-    schema: Schema,
-    stats: Vec<ColumnStats>,
-}
-
-impl BatchStats {
-    pub fn get_stats(&self, column: &str) -> polars_core::error::Result<&ColumnStats> {
-        self.schema.try_index_of(column).map(|i| &self.stats[i])
-    }
+fn to_min_max(st: &Statistics) -> Option<Series> {
+    let max_val = &*st.max_value;
+    let min_val = &*st.min_value;
 
-    pub fn schema(&self) -> &Schema {
-        &self.schema
+    let dtype = DataType::from(min_val.data_type());
+    if dtype.is_numeric() || matches!(dtype, DataType::Utf8) {
+        let arr = concatenate(&[min_val, max_val]).unwrap();
+        let s = Series::try_from(("", arr)).unwrap();
+        if s.null_count() > 0 {
+            None
+        } else {
+            Some(s)
+        }
+    } else {
+        None
     }
 }
 
@@ -77,12 +48,16 @@ pub(crate) fn collect_statistics(
     for fld in &arrow_schema.fields {
         let st = deserialize(fld, md)?;
         schema.with_column(fld.name.to_string(), (&fld.data_type).into());
-        stats.push(ColumnStats(st, Field::from(fld)));
+        stats.push(ColumnStats::new(
+            Field::from(fld),
+            null_count(&st),
+            to_min_max(&st),
+        ));
     }
 
     Ok(if stats.is_empty() {
         None
     } else {
-        Some(BatchStats { schema, stats })
+        Some(BatchStats::new(schema, stats))
     })
 }
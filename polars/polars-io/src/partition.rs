@@ -174,6 +174,40 @@ mod test {
             assert!(expected_df.frame_equal(&df));
         }
 
+        Ok(())
+    }
+    #[test]
+    #[cfg(feature = "ipc")]
+    fn test_ipc_stream_partition() -> Result<()> {
+        use crate::ipc::{IpcCompression, IpcStreamReader, IpcStreamWriterOption};
+        use crate::SerReader;
+        use std::io::BufReader;
+
+        use tempdir::TempDir;
+
+        let tempdir = TempDir::new("ipc-stream-partition")?;
+
+        let df = df!("a" => [1, 1, 2], "b" => [2, 3, 4])?;
+        let rootdir = tempdir.path();
+
+        let option = IpcStreamWriterOption::new().with_compression(Some(IpcCompression::ZSTD));
+        PartitionedWriter::new(option, &rootdir, ["a"]).finish(&df)?;
+
+        for (dir, expected_df) in [
+            ("a=1", df!("a" => [1, 1], "b" => [2, 3])?),
+            ("a=2", df!("a" => [2], "b" => [4])?),
+        ] {
+            let paths = std::fs::read_dir(rootdir.join(dir))?
+                .map(|e| Ok(e?.path()))
+                .collect::<Result<Vec<_>>>()?;
+            assert_eq!(paths.len(), 1);
+            assert_eq!(paths[0].extension().unwrap(), "arrows");
+
+            let reader = BufReader::new(std::fs::File::open(&paths[0])?);
+            let df = IpcStreamReader::new(reader).finish()?;
+            assert!(expected_df.frame_equal(&df));
+        }
+
         Ok(())
     }
 }
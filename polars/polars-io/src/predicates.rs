@@ -8,15 +8,122 @@ pub trait PhysicalIoExpr: Send + Sync {
     /// Can take &dyn Statistics and determine of a file should be
     /// read -> `true`
     /// or not -> `false`
-    #[cfg(feature = "parquet")]
+    #[cfg(any(feature = "parquet", feature = "ipc"))]
     fn as_stats_evaluator(&self) -> Option<&dyn StatsEvaluator> {
         None
     }
+
+    /// The names of the columns the predicate depends on, if known.
+    #[cfg(feature = "ipc")]
+    fn live_columns(&self) -> Option<Vec<String>> {
+        None
+    }
 }
 
-#[cfg(feature = "parquet")]
+#[cfg(any(feature = "parquet", feature = "ipc"))]
 pub trait StatsEvaluator {
-    fn should_read(&self, stats: &crate::parquet::predicates::BatchStats) -> Result<bool>;
+    fn should_read(&self, stats: &BatchStats) -> Result<bool>;
+}
+
+/// The statistics for a column in a batch of data
+/// they typically hold
+/// - max value
+/// - min value
+/// - null_count
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct ColumnStats {
+    field: Field,
+    null_count: Option<usize>,
+    min_max: Option<Series>,
+    bool_counts: Option<(usize, usize)>,
+}
+
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+impl ColumnStats {
+    /// `min_max` is a `Series` of length 2 with the min and the max value, in that order.
+    pub fn new(field: Field, null_count: Option<usize>, min_max: Option<Series>) -> Self {
+        Self {
+            field,
+            null_count,
+            min_max,
+            bool_counts: None,
+        }
+    }
+
+    /// Compute the statistics of the values of a `Series`.
+    pub fn from_series(s: &Series) -> Self {
+        if let Ok(ca) = s.bool() {
+            return Self::from_bool(ca);
+        }
+        let min_max = if s.dtype().is_numeric() || matches!(s.dtype(), DataType::Utf8) {
+            let mut min_max = s.min_as_series();
+            let appended = min_max.append(&s.max_as_series()).is_ok();
+            if appended && min_max.len() == 2 && min_max.null_count() == 0 {
+                Some(min_max)
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+        Self::new(s.field().into_owned(), Some(s.null_count()), min_max)
+    }
+
+    /// The statistics of a boolean column follow from the number of `true`, `false` and
+    /// null values.
+    fn from_bool(ca: &BooleanChunked) -> Self {
+        let null_count = ca.null_count();
+        let true_count = ca.sum().unwrap_or(0) as usize;
+        let false_count = ca.len() - null_count - true_count;
+        let min_max = (true_count + false_count > 0)
+            .then(|| Series::new(ca.name(), &[false_count == 0, true_count > 0]));
+        Self {
+            field: Field::new(ca.name(), DataType::Boolean),
+            null_count: Some(null_count),
+            min_max,
+            bool_counts: Some((true_count, false_count)),
+        }
+    }
+
+    /// The number of `true` and `false` values of a boolean column, in that order.
+    pub fn bool_counts(&self) -> Option<(usize, usize)> {
+        self.bool_counts
+    }
+
+    pub fn dtype(&self) -> DataType {
+        self.field.data_type().clone()
+    }
+
+    pub fn null_count(&self) -> Option<usize> {
+        self.null_count
+    }
+
+    pub fn to_min_max(&self) -> Option<Series> {
+        self.min_max.clone()
+    }
+}
+
+/// A collection of column stats with a known schema.
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+pub struct BatchStats {
+    schema: Schema,
+    stats: Vec<ColumnStats>,
+}
+
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+impl BatchStats {
+    pub fn new(schema: Schema, stats: Vec<ColumnStats>) -> Self {
+        Self { schema, stats }
+    }
+
+    pub fn get_stats(&self, column: &str) -> polars_core::error::Result<&ColumnStats> {
+        self.schema.try_index_of(column).map(|i| &self.stats[i])
+    }
+
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
 }
 
 #[cfg(feature = "parquet")]
@@ -66,6 +66,20 @@ pub(crate) fn columns_to_projection(
     Ok(prj)
 }
 
+/// Cast the columns of `df` that are named in `schema_overwrite` to the given dtype.
+/// Columns not in the `schema_overwrite` are left untouched.
+#[cfg(any(feature = "ipc", feature = "json", feature = "avro"))]
+pub(crate) fn apply_schema_overwrite(df: &mut DataFrame, schema_overwrite: &Schema) -> Result<()> {
+    for (name, dtype) in schema_overwrite.iter() {
+        if let Some(idx) = df.find_idx_by_name(name) {
+            if df.get_columns()[idx].dtype() != dtype {
+                df.try_apply_at_idx(idx, |s| s.cast(dtype))?;
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Because of threading every row starts from `0` or from `offset`.
 /// We must correct that so that they are monotonically increasing.
 pub(crate) fn update_row_counts(dfs: &mut [(DataFrame, IdxSize)]) {
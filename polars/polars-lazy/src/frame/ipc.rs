@@ -8,6 +8,7 @@ pub struct ScanArgsIpc {
     pub cache: bool,
     pub rechunk: bool,
     pub row_count: Option<RowCount>,
+    pub statistics: bool,
 }
 
 impl Default for ScanArgsIpc {
@@ -17,6 +18,7 @@ impl Default for ScanArgsIpc {
             cache: true,
             rechunk: true,
             row_count: None,
+            statistics: false,
         }
     }
 }
@@ -29,6 +31,7 @@ impl LazyFrame {
             with_columns: None,
             row_count: args.row_count,
             rechunk: args.rechunk,
+            statistics: args.statistics,
         };
         let mut lf: LazyFrame = LogicalPlanBuilder::scan_ipc(path, options)?.build().into();
         lf.opt_state.agg_scan_projection = true;
@@ -44,6 +44,7 @@ pub struct IpcScanOptions {
     pub cache: bool,
     pub row_count: Option<RowCount>,
     pub rechunk: bool,
+    pub statistics: bool,
 }
 
 #[derive(Clone, Debug, Copy, Default)]
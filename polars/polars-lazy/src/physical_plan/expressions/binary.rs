@@ -374,16 +374,16 @@ impl PhysicalExpr for BinaryExpr {
         Some(self)
     }
 
-    #[cfg(feature = "parquet")]
+    #[cfg(any(feature = "parquet", feature = "ipc"))]
     fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
         Some(self)
     }
 }
 
-#[cfg(feature = "parquet")]
+#[cfg(any(feature = "parquet", feature = "ipc"))]
 mod stats {
     use super::*;
-    use polars_io::parquet::predicates::BatchStats;
+    use polars_io::predicates::BatchStats;
     use polars_io::predicates::StatsEvaluator;
 
     fn apply_operator_stats_rhs_lit(min_max: &Series, literal: &Series, op: Operator) -> bool {
@@ -3,10 +3,10 @@ use crate::prelude::*;
 use crate::utils::expr_to_root_column_name;
 use polars_core::frame::groupby::GroupsProxy;
 use polars_core::prelude::*;
-#[cfg(feature = "parquet")]
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+use polars_io::predicates::BatchStats;
+#[cfg(any(feature = "parquet", feature = "ipc"))]
 use polars_io::predicates::StatsEvaluator;
-#[cfg(feature = "parquet")]
-use polars_io::prelude::predicates::BatchStats;
 use std::sync::Arc;
 
 pub struct IsNullExpr {
@@ -50,7 +50,7 @@ impl PhysicalExpr for IsNullExpr {
     fn to_field(&self, _input_schema: &Schema) -> Result<Field> {
         Ok(Field::new("is_null", DataType::Boolean))
     }
-    #[cfg(feature = "parquet")]
+    #[cfg(any(feature = "parquet", feature = "ipc"))]
     fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
         Some(self)
     }
@@ -59,7 +59,7 @@ impl PhysicalExpr for IsNullExpr {
     }
 }
 
-#[cfg(feature = "parquet")]
+#[cfg(any(feature = "parquet", feature = "ipc"))]
 impl StatsEvaluator for IsNullExpr {
     fn should_read(&self, stats: &BatchStats) -> Result<bool> {
         let root = expr_to_root_column_name(&self.expr)?;
@@ -561,7 +561,7 @@ pub trait PhysicalExpr: Send + Sync {
     /// Can take &dyn Statistics and determine of a file should be
     /// read -> `true`
     /// or not -> `false`
-    #[cfg(feature = "parquet")]
+    #[cfg(any(feature = "parquet", feature = "ipc"))]
     fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
         None
     }
@@ -579,10 +579,21 @@ impl PhysicalIoExpr for PhysicalIoHelper {
         self.expr.evaluate(df, &Default::default())
     }
 
-    #[cfg(feature = "parquet")]
+    #[cfg(any(feature = "parquet", feature = "ipc"))]
     fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
         self.expr.as_stats_evaluator()
     }
+
+    #[cfg(feature = "ipc")]
+    fn live_columns(&self) -> Option<Vec<String>> {
+        crate::utils::expr_to_root_column_exprs(self.expr.as_expression())
+            .into_iter()
+            .map(|e| match e {
+                Expr::Column(name) => Some(name.to_string()),
+                _ => None,
+            })
+            .collect()
+    }
 }
 
 pub trait PartitionedAggregation: Send + Sync + PhysicalExpr {
@@ -180,6 +180,7 @@ fn test_ipc_globbing() -> Result<()> {
             cache: true,
             rechunk: false,
             row_count: None,
+            statistics: false,
         },
     )?
     .collect()?;
@@ -191,6 +192,34 @@ fn test_ipc_globbing() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_ipc_statistics_skip_batches() -> Result<()> {
+    // the first record batch holds an invalid utf8 value, so the scan only succeeds if the
+    // statistics of the `days` column skip that batch before it is decoded
+    let mut df = df!["days" => [0i32, 1], "name" => ["a", "XXXXXXXX"]]?;
+    df.vstack_mut(&df!["days" => [2i32, 3], "name" => ["c", "d"]]?)?;
+    let mut buf = Vec::new();
+    IpcWriter::new(&mut buf).finish(&mut df)?;
+    let pos = buf.windows(8).position(|w| w == b"XXXXXXXX").unwrap();
+    buf[pos] = 0xff;
+    let path = std::env::temp_dir().join("polars-lazy-ipc-statistics.ipc");
+    std::fs::write(&path, &buf)?;
+
+    let scan = |statistics| {
+        let args = ScanArgsIpc {
+            cache: false,
+            statistics,
+            ..Default::default()
+        };
+        LazyFrame::scan_ipc(path.to_string_lossy().into_owned(), args)
+            .map(|lf| lf.filter(col("days").gt_eq(lit(2))))
+    };
+    let out = scan(true)?.collect()?;
+    assert!(out.frame_equal(&df!["days" => [2i32, 3], "name" => ["c", "d"]]?));
+    assert!(scan(false)?.collect().is_err());
+    Ok(())
+}
+
 fn slice_at_union(lp_arena: &Arena<ALogicalPlan>, lp: Node) -> bool {
     (&lp_arena).iter(lp).all(|(_, lp)| {
         if let ALogicalPlan::Union { options, .. } = lp {
@@ -45,6 +45,21 @@ fn test_lazy_exec() {
     assert!(check.all())
 }
 
+#[test]
+fn test_lazy_any_all_with_nulls() -> Result<()> {
+    let df = df![
+        "a" => [Some(true), None, Some(true)]
+    ]?;
+    let out = df
+        .lazy()
+        .select([col("a").any().alias("any"), col("a").all().alias("all")])
+        .collect()?;
+    // a null value makes `all` false
+    assert_eq!(Vec::from(out.column("any")?.bool()?), &[Some(true)]);
+    assert_eq!(Vec::from(out.column("all")?.bool()?), &[Some(false)]);
+    Ok(())
+}
+
 #[test]
 fn test_lazy_alias() {
     let df = get_df();
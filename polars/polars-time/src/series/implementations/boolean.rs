@@ -4,4 +4,64 @@ impl SeriesOpsTime for Wrap<BooleanChunked> {
     fn ops_time_dtype(&self) -> &DataType {
         self.0.dtype()
     }
+
+    /// The fraction of `true` values in the window. Booleans are cast to `Float64` first.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_mean(&self, options: RollingOptionsImpl) -> Result<Series> {
+        self.0.cast(&DataType::Float64)?.rolling_mean(options)
+    }
+
+    /// The number of `true` values in the window. Booleans are cast to the index type first.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_sum(&self, options: RollingOptionsImpl) -> Result<Series> {
+        self.0.cast(&IDX_DTYPE)?.rolling_sum(options)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "rolling_window")]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_sum_mean_bool() -> Result<()> {
+        let s = Series::new(
+            "a",
+            &[
+                Some(true),
+                Some(false),
+                None,
+                Some(true),
+                Some(true),
+                Some(false),
+            ],
+        );
+        let options = RollingOptions {
+            window_size: Duration::new(3),
+            min_periods: 2,
+            ..Default::default()
+        };
+
+        let out = s.rolling_sum(options.clone().into())?;
+        let expected = s.cast(&IDX_DTYPE)?.rolling_sum(options.clone().into())?;
+        assert_eq!(out.dtype(), &IDX_DTYPE);
+        assert!(out.series_equal_missing(&expected));
+
+        let out = s.rolling_mean(options.clone().into())?;
+        let expected = s.cast(&DataType::Float64)?.rolling_mean(options.into())?;
+        assert_eq!(out.dtype(), &DataType::Float64);
+        assert!(out.series_equal_missing(&expected));
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[
+                None,
+                Some(0.5),
+                Some(0.5),
+                Some(0.5),
+                Some(1.0),
+                Some(2.0 / 3.0)
+            ]
+        );
+        Ok(())
+    }
 }
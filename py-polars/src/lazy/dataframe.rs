@@ -252,6 +252,7 @@ impl PyLazyFrame {
             cache,
             rechunk,
             row_count,
+            statistics: false,
         };
         let lf = LazyFrame::scan_ipc(path, args).map_err(PyPolarsErr::from)?;
         Ok(lf.into())